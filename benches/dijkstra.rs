@@ -0,0 +1,177 @@
+//! Compares `dijkstra_search` against the implementation it replaced,
+//! which kept its open list sorted in a `VecDeque` with `priority_queue_insert`.
+//!
+//! Run with `cargo bench --bench dijkstra`.
+
+use std::{
+    collections::{HashSet, VecDeque},
+    hash::Hash,
+};
+
+use aoc24::{
+    algorithms::{dijkstra_search, priority_queue_insert},
+    bench::{measure, BenchConfig, Stats},
+    direction::{Direction, DIRECTIONS},
+    grid::Grid,
+    input::{default_data_dir, input_path, read_input},
+    position::{pos, Position},
+};
+
+fn vecdeque_dijkstra_search<T, Succ, GPred>(
+    start: &[T],
+    get_successors: Succ,
+    is_goal: GPred,
+) -> Option<i64>
+where
+    T: Hash + Eq + Copy,
+    Succ: Fn(&T) -> Vec<(T, i64)>,
+    GPred: Fn(&T) -> bool,
+{
+    let mut open_list = VecDeque::<(T, i64)>::new();
+    let mut closed_set = HashSet::<T>::new();
+
+    for s in start {
+        open_list.push_back((*s, 0));
+    }
+
+    while let Some((value, cost)) = open_list.pop_front() {
+        if is_goal(&value) {
+            return Some(cost);
+        }
+
+        closed_set.insert(value);
+
+        for (successor_val, successor_cost) in get_successors(&value) {
+            if closed_set.contains(&successor_val) {
+                continue;
+            }
+
+            priority_queue_insert(&mut open_list, successor_val, cost + successor_cost);
+        }
+    }
+
+    None
+}
+
+/// The signature shared by both implementations,
+/// specialised to a boxed successor function so they can be swapped freely.
+type Search<T> = fn(&[T], &dyn Fn(&T) -> Vec<(T, i64)>, &dyn Fn(&T) -> bool) -> Option<i64>;
+
+fn heap<T: Hash + Eq + Copy>(
+    start: &[T],
+    succ: &dyn Fn(&T) -> Vec<(T, i64)>,
+    goal: &dyn Fn(&T) -> bool,
+) -> Option<i64> {
+    dijkstra_search(start, succ, goal)
+}
+
+fn vecdeque<T: Hash + Eq + Copy>(
+    start: &[T],
+    succ: &dyn Fn(&T) -> Vec<(T, i64)>,
+    goal: &dyn Fn(&T) -> bool,
+) -> Option<i64> {
+    vecdeque_dijkstra_search(start, succ, goal)
+}
+
+/// Day 16 part 1: the reindeer maze, where turning costs 1000.
+fn day16(search: Search<(Position, Direction)>, grid: &Grid<char>) -> Option<i64> {
+    let start = grid.position(|c| *c == 'S').unwrap();
+    search(
+        &[(start, Direction::Right)],
+        &|&(pos, dir)| {
+            let forward = pos.move_in_direction(dir);
+            let mut successors = vec![
+                ((pos, dir.rotate_cw()), 1000),
+                ((pos, dir.rotate_ccw()), 1000),
+            ];
+            if *grid.get_pos(&forward) != '#' {
+                successors.push(((forward, dir), 1));
+            }
+            successors
+        },
+        &|(pos, _)| *grid.get_pos(pos) == 'E',
+    )
+}
+
+/// Day 18: the shortest path across a 71x71 grid after some bytes have fallen.
+fn day18_path(search: Search<Position>, obstacles: &[Position]) -> Option<i64> {
+    let obstacles = obstacles.iter().copied().collect::<HashSet<_>>();
+    search(
+        &[pos(0, 0)],
+        &|p| {
+            DIRECTIONS
+                .iter()
+                .map(|d| p.move_in_direction(*d))
+                .filter(|c| {
+                    (0..71).contains(&c.x) && (0..71).contains(&c.y) && !obstacles.contains(c)
+                })
+                .map(|c| (c, 1))
+                .collect()
+        },
+        &|p| *p == pos(70, 70),
+    )
+}
+
+/// Day 18 part 2: binary search for the first byte that cuts off the exit,
+/// running one search per probe.
+fn day18_cutoff(search: Search<Position>, coords: &[Position]) -> usize {
+    (1..=coords.len())
+        .collect::<Vec<_>>()
+        .partition_point(|&n| day18_path(search, &coords[0..n]).is_some())
+}
+
+fn load(day: u32) -> String {
+    let path = input_path(&default_data_dir(), day, "input");
+    read_input(&path).unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e))
+}
+
+fn report(name: &str, old: Stats, new: Stats) {
+    println!(
+        "{:<14}  {:>12.1}  {:>12.1}  {:>7.1}x",
+        name,
+        old.median,
+        new.median,
+        old.median / new.median
+    );
+}
+
+fn main() {
+    let config = BenchConfig {
+        warmup: 1,
+        iterations: 5,
+    };
+
+    let lines = load(16).lines().map(|l| l.to_string()).collect::<Vec<_>>();
+    let grid = Grid::from_strings(&lines);
+
+    let coords = load(18)
+        .lines()
+        .map(|l| {
+            let (x, y) = l.split_once(',').unwrap();
+            pos(x.parse().unwrap(), y.parse().unwrap())
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(day16(heap, &grid), day16(vecdeque, &grid));
+    assert_eq!(day18_cutoff(heap, &coords), day18_cutoff(vecdeque, &coords));
+
+    println!(
+        "{:<14}  {:>12}  {:>12}  {:>8}",
+        "Median µs", "VecDeque", "BinaryHeap", "Speedup"
+    );
+    report(
+        "day16 part 1",
+        measure(&config, || day16(vecdeque, &grid)),
+        measure(&config, || day16(heap, &grid)),
+    );
+    report(
+        "day18 part 1",
+        measure(&config, || day18_path(vecdeque, &coords[0..1024])),
+        measure(&config, || day18_path(heap, &coords[0..1024])),
+    );
+    report(
+        "day18 part 2",
+        measure(&config, || day18_cutoff(vecdeque, &coords)),
+        measure(&config, || day18_cutoff(heap, &coords)),
+    );
+}
@@ -1,16 +1,57 @@
 use std::{
-    collections::{HashSet, VecDeque},
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
     hash::Hash,
 };
 
-/// Returns (steps before loop, loop length).
+use crate::{grid::Grid, position::Position};
+
+/// Returns (steps before loop, loop length) for a sequence that
+/// eventually repeats, or `None` if the iterator ends first.
+///
+/// Steps before loop is the index of the first value in the loop,
+/// and `x[i] == x[i + loop length]` for every `i` from there on.
 ///
-/// Steps before loop is an overestimation.
-/// It is always some multiple of the loop length.
-/// After taking that many steps you are guaranteed
-/// to be inside the loop, but it doesn't tell you
-/// exactly where the loop starts.
+/// Uses Floyd's algorithm, cloning the iterator to restart it,
+/// so the iterator must yield the same sequence every time it is cloned.
 pub fn detect_loop<T, A>(it: &A) -> Option<(usize, usize)>
+where
+    T: Eq,
+    A: Iterator<Item = T> + Clone,
+{
+    let (mut a, mut tortoise) = floyd_meet(it)?;
+
+    // The tortoise is now a whole number of loops from the start,
+    // so stepping it alongside a fresh iterator brings them together
+    // exactly where the loop begins.
+    let mut c = it.clone();
+    let mut from_start = c.next()?;
+    let mut steps_before_loop = 0;
+    while from_start != tortoise {
+        from_start = c.next()?;
+        tortoise = a.next()?;
+        steps_before_loop += 1;
+    }
+
+    let loop_length = a.take_while(|x| *x != tortoise).count() + 1;
+
+    Some((steps_before_loop, loop_length))
+}
+
+/// Whether the sequence repeats before the iterator ends.
+///
+/// Cheaper than [`detect_loop`] when the loop itself is not needed.
+pub fn is_looping<T, A>(it: &A) -> bool
+where
+    T: Eq,
+    A: Iterator<Item = T> + Clone,
+{
+    floyd_meet(it).is_some()
+}
+
+/// Runs the tortoise and hare until they meet,
+/// returning the tortoise's iterator and the value they met at.
+fn floyd_meet<T, A>(it: &A) -> Option<(A, T)>
 where
     T: Eq,
     A: Iterator<Item = T> + Clone,
@@ -18,22 +59,84 @@ where
     let mut a = it.clone();
     let mut b = it.clone();
 
+    // The tortoise visits x[i] while the hare visits x[2i].
+    a.next()?;
     let mut tortoise = a.next()?;
     b.next()?;
+    b.next()?;
     let mut hare = b.next()?;
 
-    let mut steps = 1;
-
     while tortoise != hare {
         tortoise = a.next()?;
         b.next()?;
         hare = b.next()?;
-        steps += 1;
     }
 
-    let loop_length = a.take_while(|x| *x != hare).count() + 1;
+    Some((a, tortoise))
+}
+
+/// Returns (steps before loop, loop length) for the sequence
+/// `start, step(start), step(step(start)), ...`,
+/// which must eventually repeat.
+///
+/// Uses Brent's algorithm, which calls `step` fewer times than Floyd's.
+pub fn detect_cycle<T, F>(start: &T, step: F) -> (usize, usize)
+where
+    T: Eq + Clone,
+    F: Fn(&T) -> T,
+{
+    // Find the loop length by moving the tortoise up to the hare
+    // at every power of two until the hare laps it.
+    let mut power = 1;
+    let mut loop_length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(start);
+    while tortoise != hare {
+        if power == loop_length {
+            tortoise = hare.clone();
+            power *= 2;
+            loop_length = 0;
+        }
+        hare = step(&hare);
+        loop_length += 1;
+    }
 
-    Some((steps, loop_length))
+    // With the hare one loop ahead, they meet where the loop begins.
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    for _ in 0..loop_length {
+        hare = step(&hare);
+    }
+    let mut steps_before_loop = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        steps_before_loop += 1;
+    }
+
+    (steps_before_loop, loop_length)
+}
+
+/// Returns the state after applying `step` to `start` `n` times,
+/// skipping whole loops so that `n` can be far larger
+/// than the number of distinct states.
+pub fn nth_state_with_cycle<T, F>(start: &T, step: F, n: usize) -> T
+where
+    T: Eq + Clone,
+    F: Fn(&T) -> T,
+{
+    let (steps_before_loop, loop_length) = detect_cycle(start, &step);
+    let steps = if n < steps_before_loop {
+        n
+    } else {
+        steps_before_loop + (n - steps_before_loop) % loop_length
+    };
+
+    let mut state = start.clone();
+    for _ in 0..steps {
+        state = step(&state);
+    }
+    state
 }
 
 pub fn flood_fill<T, I, F>(start: T, mut succ: F) -> HashSet<T>
@@ -77,6 +180,334 @@ where
     seen
 }
 
+/// The result of a breadth-first search.
+#[derive(Debug, Clone)]
+pub struct Bfs<T> {
+    /// The number of steps from the nearest start to every visited value.
+    pub distances: HashMap<T, usize>,
+    /// The value each visited value was first reached from.
+    /// Start values have no parent.
+    pub parents: HashMap<T, T>,
+    /// The first value found satisfying the target predicate, if any.
+    pub target: Option<T>,
+}
+
+impl<T: Hash + Eq + Copy> Bfs<T> {
+    pub fn distance(&self, value: &T) -> Option<usize> {
+        self.distances.get(value).copied()
+    }
+
+    /// A shortest path from a start to `value`, inclusive of both ends,
+    /// or `None` if `value` was not visited.
+    pub fn path_to(&self, value: &T) -> Option<Vec<T>> {
+        self.distances.get(value)?;
+
+        let mut path = vec![*value];
+        let mut current = *value;
+        while let Some(&previous) = self.parents.get(&current) {
+            path.push(previous);
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// A shortest path to the target, if one was reached.
+    pub fn target_path(&self) -> Option<Vec<T>> {
+        self.path_to(&self.target?)
+    }
+}
+
+/// Visits everything reachable from the start values in order of distance,
+/// where every step costs 1.
+pub fn bfs<T, I, F>(start: &[T], get_successors: F) -> Bfs<T>
+where
+    T: Eq + Hash + Copy,
+    I: IntoIterator<Item = T>,
+    F: FnMut(&T) -> I,
+{
+    bfs_until(start, get_successors, |_| false)
+}
+
+/// Like [`bfs`], but stops as soon as a value satisfying `is_target` is visited.
+///
+/// Values further from the start than the target may still be missing
+/// from the distances.
+pub fn bfs_until<T, I, F, GPred>(start: &[T], mut get_successors: F, is_target: GPred) -> Bfs<T>
+where
+    T: Eq + Hash + Copy,
+    I: IntoIterator<Item = T>,
+    F: FnMut(&T) -> I,
+    GPred: Fn(&T) -> bool,
+{
+    let mut queue = VecDeque::new();
+    let mut distances = HashMap::new();
+    let mut parents = HashMap::new();
+
+    for s in start {
+        if distances.insert(*s, 0).is_none() {
+            queue.push_back(*s);
+        }
+    }
+
+    while let Some(value) = queue.pop_front() {
+        if is_target(&value) {
+            return Bfs {
+                distances,
+                parents,
+                target: Some(value),
+            };
+        }
+
+        let distance = distances[&value] + 1;
+        for successor in get_successors(&value) {
+            if let Entry::Vacant(e) = distances.entry(successor) {
+                e.insert(distance);
+                parents.insert(successor, value);
+                queue.push_back(successor);
+            }
+        }
+    }
+
+    Bfs {
+        distances,
+        parents,
+        target: None,
+    }
+}
+
+/// Breadth-first search over the four-way neighbours of a grid,
+/// returning the number of steps to every cell from the nearest start,
+/// or `None` for cells that cannot be reached.
+///
+/// A step from one cell to a neighbour is allowed if `can_move(from, to)`.
+pub fn grid_bfs<T, F>(grid: &Grid<T>, start: &[Position], can_move: F) -> Grid<Option<u32>>
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let mut distances = Grid {
+        width: grid.width,
+        vec: vec![None; grid.vec.len()],
+    };
+    let mut queue = VecDeque::new();
+
+    for s in start {
+        if let Some(i) = grid.pos_to_vec_index(s) {
+            if distances.vec[i].is_none() {
+                distances.vec[i] = Some(0);
+                queue.push_back(*s);
+            }
+        }
+    }
+
+    while let Some(p) = queue.pop_front() {
+        let from = grid.get_pos(&p);
+        let distance = distances.get_pos(&p).unwrap() + 1;
+        for (n, to) in grid.neighbours(p) {
+            let i = grid.pos_to_vec_index(&n).unwrap();
+            if distances.vec[i].is_none() && can_move(from, to) {
+                distances.vec[i] = Some(distance);
+                queue.push_back(n);
+            }
+        }
+    }
+
+    distances
+}
+
+/// Union-find over the values `0..len`,
+/// for tracking which values are connected as links are added.
+#[derive(Debug, Clone)]
+pub struct IndexDisjointSet {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    components: usize,
+}
+
+impl IndexDisjointSet {
+    /// Creates `len` values, each in a component of its own.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            components: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Adds a new value in a component of its own, returning its index.
+    pub fn push(&mut self) -> usize {
+        let i = self.parents.len();
+        self.parents.push(i);
+        self.sizes.push(1);
+        self.components += 1;
+        i
+    }
+
+    /// Returns the representative of the component containing `i`.
+    pub fn find(&mut self, mut i: usize) -> usize {
+        // Path halving: point every other value on the way at its grandparent.
+        while self.parents[i] != i {
+            self.parents[i] = self.parents[self.parents[i]];
+            i = self.parents[i];
+        }
+        i
+    }
+
+    /// Merges the components containing `a` and `b`,
+    /// returning `false` if they were already the same component.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let a = self.find(a);
+        let b = self.find(b);
+        if a == b {
+            return false;
+        }
+        let (big, small) = if self.sizes[a] >= self.sizes[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parents[small] = big;
+        self.sizes[big] += self.sizes[small];
+        self.components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The number of values in the component containing `i`.
+    pub fn size(&mut self, i: usize) -> usize {
+        let root = self.find(i);
+        self.sizes[root]
+    }
+
+    /// The number of distinct components.
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// Every component, as the values it contains in increasing order.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut by_root = HashMap::<usize, Vec<usize>>::new();
+        for i in 0..self.len() {
+            let root = self.find(i);
+            by_root.entry(root).or_default().push(i);
+        }
+        let mut components = by_root.into_values().collect::<Vec<_>>();
+        components.sort();
+        components
+    }
+}
+
+/// Union-find over arbitrary hashable values,
+/// which are added the first time they are seen.
+#[derive(Debug, Clone)]
+pub struct DisjointSet<T> {
+    indices: HashMap<T, usize>,
+    values: Vec<T>,
+    sets: IndexDisjointSet,
+}
+
+impl<T: Hash + Eq + Clone> Default for DisjointSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Hash + Eq + Clone> DisjointSet<T> {
+    pub fn new() -> Self {
+        Self {
+            indices: HashMap::new(),
+            values: Vec::new(),
+            sets: IndexDisjointSet::new(0),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        self.indices.contains_key(value)
+    }
+
+    /// Adds a value in a component of its own if it is not already present.
+    pub fn insert(&mut self, value: T) {
+        self.index_of(value);
+    }
+
+    fn index_of(&mut self, value: T) -> usize {
+        match self.indices.entry(value) {
+            Entry::Occupied(e) => *e.get(),
+            Entry::Vacant(e) => {
+                let i = self.sets.push();
+                self.values.push(e.key().clone());
+                e.insert(i);
+                i
+            }
+        }
+    }
+
+    /// Returns the representative of the component containing `value`,
+    /// or `None` if it has never been added.
+    pub fn find(&mut self, value: &T) -> Option<T> {
+        let i = *self.indices.get(value)?;
+        let root = self.sets.find(i);
+        Some(self.values[root].clone())
+    }
+
+    /// Merges the components containing `a` and `b`, adding either if needed,
+    /// and returns `false` if they were already the same component.
+    pub fn union(&mut self, a: T, b: T) -> bool {
+        let a = self.index_of(a);
+        let b = self.index_of(b);
+        self.sets.union(a, b)
+    }
+
+    pub fn connected(&mut self, a: &T, b: &T) -> bool {
+        match (self.indices.get(a), self.indices.get(b)) {
+            (Some(&a), Some(&b)) => self.sets.connected(a, b),
+            _ => false,
+        }
+    }
+
+    /// The number of values in the component containing `value`,
+    /// or 0 if it has never been added.
+    pub fn size(&mut self, value: &T) -> usize {
+        match self.indices.get(value) {
+            Some(&i) => self.sets.size(i),
+            None => 0,
+        }
+    }
+
+    /// The number of distinct components.
+    pub fn component_count(&self) -> usize {
+        self.sets.component_count()
+    }
+
+    /// Every component, as the values it contains in the order they were added.
+    pub fn components(&mut self) -> Vec<Vec<T>> {
+        self.sets
+            .components()
+            .into_iter()
+            .map(|c| c.into_iter().map(|i| self.values[i].clone()).collect())
+            .collect()
+    }
+}
+
 /// Inserts the given value into the queue
 /// with the given priority.
 ///
@@ -107,6 +538,39 @@ pub fn priority_queue_insert<T: Eq>(queue: &mut VecDeque<(T, i64)>, value: T, pr
     queue.insert(insert_index.unwrap_or(queue.len()), (value, priority));
 }
 
+/// An entry in the open list of a search,
+/// ordered so that a `BinaryHeap` pops the cheapest entry first.
+struct OpenEntry<T> {
+    cost: i64,
+    value: T,
+}
+
+impl<T> PartialEq for OpenEntry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl<T> Eq for OpenEntry<T> {}
+
+impl<T> PartialOrd for OpenEntry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for OpenEntry<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.cmp(&self.cost)
+    }
+}
+
+/// Returns the cost of the cheapest path from any of the start values
+/// to a value satisfying `is_goal`, or `None` if no goal is reachable.
+///
+/// The open list is a binary heap with lazy decrease-key:
+/// when a cheaper route to a value is found, a new entry is pushed
+/// and the old one is skipped when it eventually comes off the heap.
 pub fn dijkstra_search<T, Succ, GPred>(
     start: &[T],
     get_successors: Succ,
@@ -117,28 +581,573 @@ where
     Succ: Fn(&T) -> Vec<(T, i64)>,
     GPred: Fn(&T) -> bool,
 {
-    let mut open_list = VecDeque::<(T, i64)>::new();
-    let mut closed_set = HashSet::<T>::new();
+    let mut open_list = BinaryHeap::<OpenEntry<T>>::new();
+    let mut best_costs = HashMap::<T, i64>::new();
 
     for s in start {
-        open_list.push_back((*s, 0));
+        best_costs.insert(*s, 0);
+        open_list.push(OpenEntry { cost: 0, value: *s });
     }
 
-    while let Some((value, cost)) = open_list.pop_front() {
+    while let Some(OpenEntry { cost, value }) = open_list.pop() {
+        if best_costs.get(&value).is_some_and(|&best| cost > best) {
+            // A stale entry for a value we since found a cheaper route to.
+            continue;
+        }
+
         if is_goal(&value) {
             return Some(cost);
         }
 
-        closed_set.insert(value);
+        for (successor_val, successor_cost) in get_successors(&value) {
+            let new_cost = cost + successor_cost;
+            match best_costs.entry(successor_val) {
+                Entry::Occupied(e) if *e.get() <= new_cost => continue,
+                Entry::Occupied(mut e) => {
+                    e.insert(new_cost);
+                }
+                Entry::Vacant(e) => {
+                    e.insert(new_cost);
+                }
+            }
+            open_list.push(OpenEntry {
+                cost: new_cost,
+                value: successor_val,
+            });
+        }
+    }
+
+    None
+}
+
+/// Returns the cost of the cheapest path from any of the start values
+/// to a value satisfying `is_goal`, or `None` if no goal is reachable.
+///
+/// Like [`dijkstra_search`], but the open list is ordered by
+/// cost so far plus `heuristic`, an estimate of the remaining cost.
+/// The result is only guaranteed to be the cheapest
+/// if the heuristic never overestimates.
+pub fn astar_search<T, Succ, GPred, H>(
+    start: &[T],
+    get_successors: Succ,
+    is_goal: GPred,
+    heuristic: H,
+) -> Option<i64>
+where
+    T: Hash + Eq + Copy,
+    Succ: Fn(&T) -> Vec<(T, i64)>,
+    GPred: Fn(&T) -> bool,
+    H: Fn(&T) -> i64,
+{
+    // Entries are prioritised by their estimated total cost
+    // and carry the actual cost so far alongside the value.
+    let mut open_list = BinaryHeap::<OpenEntry<(T, i64)>>::new();
+    let mut best_costs = HashMap::<T, i64>::new();
+
+    for s in start {
+        best_costs.insert(*s, 0);
+        open_list.push(OpenEntry {
+            cost: heuristic(s),
+            value: (*s, 0),
+        });
+    }
+
+    while let Some(OpenEntry {
+        value: (value, cost),
+        ..
+    }) = open_list.pop()
+    {
+        if best_costs.get(&value).is_some_and(|&best| cost > best) {
+            continue;
+        }
+
+        if is_goal(&value) {
+            return Some(cost);
+        }
 
         for (successor_val, successor_cost) in get_successors(&value) {
-            if closed_set.contains(&successor_val) {
+            let new_cost = cost + successor_cost;
+            match best_costs.entry(successor_val) {
+                Entry::Occupied(e) if *e.get() <= new_cost => continue,
+                Entry::Occupied(mut e) => {
+                    e.insert(new_cost);
+                }
+                Entry::Vacant(e) => {
+                    e.insert(new_cost);
+                }
+            }
+            open_list.push(OpenEntry {
+                cost: new_cost + heuristic(&successor_val),
+                value: (successor_val, new_cost),
+            });
+        }
+    }
+
+    None
+}
+
+/// Everything a Dijkstra search learned on its way to the cheapest goal.
+///
+/// The search keeps going after the first goal is reached
+/// until every value costing no more than it has been settled,
+/// so `goals` holds every goal reachable at the optimal cost
+/// and `predecessors` describes every optimal path to them.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<T> {
+    /// The first goal reached.
+    pub goal: T,
+    /// The cost of the cheapest path to a goal.
+    pub goal_cost: i64,
+    /// Every goal reachable at `goal_cost`, starting with `goal`.
+    pub goals: Vec<T>,
+    /// The cost of the cheapest path to every settled value.
+    pub costs: HashMap<T, i64>,
+    /// For every settled value other than the starts,
+    /// each value it can be reached from on a cheapest path.
+    /// Loops of zero-cost edges are broken, so following predecessors
+    /// from any value always ends at a start.
+    pub predecessors: HashMap<T, Vec<T>>,
+}
+
+impl<T: Hash + Eq + Copy> ShortestPaths<T> {
+    /// One cheapest path from a start to `goal`, inclusive of both ends.
+    pub fn path(&self) -> Vec<T> {
+        self.path_to(&self.goal)
+            .expect("the goal is always settled")
+    }
+
+    /// One cheapest path from a start to `target`,
+    /// or `None` if `target` was not settled by the search.
+    pub fn path_to(&self, target: &T) -> Option<Vec<T>> {
+        self.costs.get(target)?;
+
+        let mut path = vec![*target];
+        let mut current = *target;
+        while let Some(&previous) = self.predecessors.get(&current).and_then(|p| p.first()) {
+            path.push(previous);
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every cheapest path from a start to any of the `goals`.
+    ///
+    /// The number of paths can grow exponentially with their length;
+    /// use [`Self::nodes_on_optimal_paths`] if only the values are needed.
+    pub fn all_paths(&self) -> Vec<Vec<T>> {
+        let mut paths = Vec::new();
+        for goal in &self.goals {
+            let mut suffix = vec![*goal];
+            self.collect_paths(&mut suffix, &mut paths);
+        }
+        paths
+    }
+
+    fn collect_paths(&self, suffix: &mut Vec<T>, paths: &mut Vec<Vec<T>>) {
+        let current = *suffix.last().unwrap();
+        match self.predecessors.get(&current) {
+            Some(previous) if !previous.is_empty() => {
+                for p in previous {
+                    suffix.push(*p);
+                    self.collect_paths(suffix, paths);
+                    suffix.pop();
+                }
+            }
+            _ => paths.push(suffix.iter().rev().copied().collect()),
+        }
+    }
+
+    /// Every value that lies on at least one cheapest path to a goal.
+    pub fn nodes_on_optimal_paths(&self) -> HashSet<T> {
+        flood_fill2(&self.goals, |v| {
+            self.predecessors.get(v).cloned().unwrap_or_default()
+        })
+    }
+}
+
+/// Searches from the start values for the cheapest goal,
+/// recording enough to reconstruct every optimal path to it.
+///
+/// Returns `None` if no goal is reachable.
+/// Costs must be non-negative.
+pub fn dijkstra<T, Succ, GPred>(
+    start: &[T],
+    get_successors: Succ,
+    is_goal: GPred,
+) -> Option<ShortestPaths<T>>
+where
+    T: Hash + Eq + Copy,
+    Succ: Fn(&T) -> Vec<(T, i64)>,
+    GPred: Fn(&T) -> bool,
+{
+    let mut open_list = BinaryHeap::<OpenEntry<T>>::new();
+    let mut best_costs = HashMap::<T, i64>::new();
+    let mut costs = HashMap::<T, i64>::new();
+    let mut predecessors = HashMap::<T, Vec<T>>::new();
+    let mut goals = Vec::new();
+    let mut goal_cost = None;
+
+    for s in start {
+        best_costs.insert(*s, 0);
+        open_list.push(OpenEntry { cost: 0, value: *s });
+    }
+
+    while let Some(OpenEntry { cost, value }) = open_list.pop() {
+        if goal_cost.is_some_and(|goal_cost| cost > goal_cost) {
+            break;
+        }
+        if costs.contains_key(&value) {
+            // A stale entry, or a value pushed twice at the same cost.
+            continue;
+        }
+        costs.insert(value, cost);
+
+        if is_goal(&value) {
+            goal_cost = Some(cost);
+            goals.push(value);
+        }
+
+        for (successor_val, successor_cost) in get_successors(&value) {
+            let new_cost = cost + successor_cost;
+            if let Some(&settled_cost) = costs.get(&successor_val) {
+                // Only a zero-cost edge can reach a settled value at its own cost.
+                // Record it unless the successor already leads back here,
+                // which would put a loop in the predecessor graph.
+                if settled_cost == new_cost
+                    && !flood_fill2(&[value], |v| {
+                        predecessors.get(v).cloned().unwrap_or_default()
+                    })
+                    .contains(&successor_val)
+                {
+                    predecessors.entry(successor_val).or_default().push(value);
+                }
                 continue;
             }
+            match best_costs.entry(successor_val) {
+                Entry::Occupied(e) if *e.get() < new_cost => continue,
+                Entry::Occupied(e) if *e.get() == new_cost => {
+                    predecessors.entry(successor_val).or_default().push(value);
+                    continue;
+                }
+                Entry::Occupied(mut e) => {
+                    e.insert(new_cost);
+                }
+                Entry::Vacant(e) => {
+                    e.insert(new_cost);
+                }
+            }
+            predecessors.insert(successor_val, vec![value]);
+            open_list.push(OpenEntry {
+                cost: new_cost,
+                value: successor_val,
+            });
+        }
+    }
+
+    let goal_cost = goal_cost?;
+    predecessors.retain(|v, _| costs.contains_key(v));
+    for s in start {
+        // A start reached again at cost 0 is still a start.
+        predecessors.remove(s);
+    }
+
+    Some(ShortestPaths {
+        goal: goals[0],
+        goal_cost,
+        goals,
+        costs,
+        predecessors,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph_successors(edges: &[(u32, u32, i64)], v: u32) -> Vec<(u32, i64)> {
+        edges
+            .iter()
+            .filter(|(from, _, _)| *from == v)
+            .map(|(_, to, cost)| (*to, *cost))
+            .collect()
+    }
+
+    #[test]
+    fn test_dijkstra_search_prefers_cheaper_longer_path() {
+        let edges = [(0, 1, 10), (0, 2, 1), (2, 3, 1), (3, 1, 1), (1, 4, 1)];
+        let cost = dijkstra_search(&[0], |v| graph_successors(&edges, *v), |v| *v == 4);
+        assert_eq!(cost, Some(4));
+    }
+
+    #[test]
+    fn test_dijkstra_search_multiple_starts() {
+        let edges = [(0, 2, 5), (1, 2, 2)];
+        let cost = dijkstra_search(&[0, 1], |v| graph_successors(&edges, *v), |v| *v == 2);
+        assert_eq!(cost, Some(2));
+    }
+
+    #[test]
+    fn test_dijkstra_search_start_is_goal() {
+        let cost = dijkstra_search(&[7], |_| vec![(8, 1)], |v| *v == 7);
+        assert_eq!(cost, Some(0));
+    }
+
+    #[test]
+    fn test_astar_search_matches_dijkstra_search() {
+        let edges = [(0, 1, 10), (0, 2, 1), (2, 3, 1), (3, 1, 1), (1, 4, 1)];
+        // Every edge costs at least 1, so this never overestimates.
+        let heuristic = |v: &u32| if *v == 4 { 0 } else { 1 };
+        let cost = astar_search(
+            &[0],
+            |v| graph_successors(&edges, *v),
+            |v| *v == 4,
+            heuristic,
+        );
+        assert_eq!(cost, Some(4));
+    }
+
+    #[test]
+    fn test_astar_search_unreachable() {
+        let edges = [(0, 1, 1), (1, 0, 1)];
+        let cost = astar_search(&[0], |v| graph_successors(&edges, *v), |v| *v == 2, |_| 0);
+        assert_eq!(cost, None);
+    }
+
+    #[test]
+    fn test_dijkstra_search_unreachable() {
+        let edges = [(0, 1, 1), (1, 0, 1)];
+        let cost = dijkstra_search(&[0], |v| graph_successors(&edges, *v), |v| *v == 2);
+        assert_eq!(cost, None);
+    }
+
+    // Two routes of cost 3 from 0 to 5 (via 1 and via 2), and a dearer one via 3.
+    const DIAMOND: [(u32, u32, i64); 7] = [
+        (0, 1, 1),
+        (0, 2, 2),
+        (1, 4, 1),
+        (2, 4, 0),
+        (4, 5, 1),
+        (0, 3, 1),
+        (3, 5, 5),
+    ];
+
+    #[test]
+    fn test_dijkstra_path() {
+        let paths = dijkstra(&[0], |v| graph_successors(&DIAMOND, *v), |v| *v == 5).unwrap();
+        assert_eq!(paths.goal, 5);
+        assert_eq!(paths.goal_cost, 3);
+        assert_eq!(paths.costs.get(&4), Some(&2));
+        assert_eq!(paths.path(), vec![0, 1, 4, 5]);
+        assert_eq!(paths.path_to(&2), Some(vec![0, 2]));
+    }
+
+    #[test]
+    fn test_dijkstra_all_paths() {
+        let paths = dijkstra(&[0], |v| graph_successors(&DIAMOND, *v), |v| *v == 5).unwrap();
+        let mut all = paths.all_paths();
+        all.sort();
+        assert_eq!(all, vec![vec![0, 1, 4, 5], vec![0, 2, 4, 5]]);
+        assert_eq!(
+            paths.nodes_on_optimal_paths(),
+            HashSet::from([0, 1, 2, 4, 5])
+        );
+    }
+
+    #[test]
+    fn test_dijkstra_equal_cost_goals() {
+        let edges = [(0, 1, 2), (0, 2, 2), (0, 3, 3)];
+        let paths = dijkstra(&[0], |v| graph_successors(&edges, *v), |v| *v != 0).unwrap();
+        let mut goals = paths.goals.clone();
+        goals.sort();
+        assert_eq!(goals, vec![1, 2]);
+        assert_eq!(paths.all_paths().len(), 2);
+    }
 
-            priority_queue_insert(&mut open_list, successor_val, cost + successor_cost);
+    #[test]
+    fn test_dijkstra_zero_cost_cycle() {
+        let edges = [(0, 1, 0), (1, 0, 0), (1, 2, 1)];
+        let paths = dijkstra(&[0], |v| graph_successors(&edges, *v), |v| *v == 2).unwrap();
+        assert_eq!(paths.all_paths(), vec![vec![0, 1, 2]]);
+    }
+
+    #[test]
+    fn test_dijkstra_zero_cost_edge_to_settled() {
+        // Whichever of 1 and 2 is settled first, the path through the
+        // zero-cost edge between them is as cheap as the direct one.
+        for (a, b) in [(1, 2), (2, 1)] {
+            let edges = [(0, a, 1), (0, b, 1), (b, a, 0), (a, 3, 1)];
+            let paths = dijkstra(&[0], |v| graph_successors(&edges, *v), |v| *v == 3).unwrap();
+            let mut all = paths.all_paths();
+            all.sort();
+            let mut expected = vec![vec![0, a, 3], vec![0, b, a, 3]];
+            expected.sort();
+            assert_eq!(all, expected);
         }
     }
 
-    None
+    #[test]
+    fn test_dijkstra_zero_cost_loop_between_settled() {
+        let edges = [
+            (0, 1, 1),
+            (0, 2, 1),
+            (1, 2, 0),
+            (2, 1, 0),
+            (1, 3, 1),
+            (2, 3, 1),
+        ];
+        let paths = dijkstra(&[0], |v| graph_successors(&edges, *v), |v| *v == 3).unwrap();
+        let all = paths.all_paths();
+        assert!(all.len() >= 2);
+        for path in &all {
+            assert_eq!((path[0], path[path.len() - 1]), (0, 3));
+        }
+        assert_eq!(paths.nodes_on_optimal_paths(), HashSet::from([0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn test_dijkstra_unreachable() {
+        let edges = [(0, 1, 1)];
+        let paths = dijkstra(&[0], |v| graph_successors(&edges, *v), |v| *v == 2);
+        assert!(paths.is_none());
+    }
+
+    #[test]
+    fn test_bfs_distances() {
+        let edges = [
+            (0, 1, 1),
+            (0, 2, 1),
+            (1, 3, 1),
+            (2, 3, 1),
+            (3, 4, 1),
+            (5, 4, 1),
+        ];
+        let result = bfs(&[0], |v| {
+            graph_successors(&edges, *v).into_iter().map(|(n, _)| n)
+        });
+        assert_eq!(result.distance(&3), Some(2));
+        assert_eq!(result.distance(&4), Some(3));
+        assert_eq!(result.distance(&5), None);
+        assert_eq!(result.target, None);
+        assert_eq!(result.path_to(&4).map(|p| p.len()), Some(4));
+    }
+
+    #[test]
+    fn test_bfs_until_multiple_sources() {
+        let succ = |v: &i64| [v - 1, v + 1];
+        let result = bfs_until(&[0, 10], succ, |v| *v == 7);
+        assert_eq!(result.target, Some(7));
+        assert_eq!(result.distance(&7), Some(3));
+        assert_eq!(result.target_path(), Some(vec![10, 9, 8, 7]));
+    }
+
+    #[test]
+    fn test_grid_bfs() {
+        let lines = ["..#", ".##", "..."].map(String::from);
+        let grid = Grid::from_strings(&lines);
+        let distances = grid_bfs(&grid, &[Position::new(0, 0)], |_, to| *to != '#');
+        assert_eq!(
+            distances.vec,
+            vec![
+                Some(0),
+                Some(1),
+                None,
+                Some(1),
+                None,
+                None,
+                Some(2),
+                Some(3),
+                Some(4)
+            ]
+        );
+    }
+
+    // 0, 1, 2, 3, 4, 5, 3, 4, 5, ...
+    fn rho(x: &u32) -> u32 {
+        if *x == 5 {
+            3
+        } else {
+            x + 1
+        }
+    }
+
+    #[test]
+    fn test_detect_loop() {
+        let it = std::iter::successors(Some(0), |x| Some(rho(x)));
+        assert_eq!(detect_loop(&it), Some((3, 3)));
+    }
+
+    #[test]
+    fn test_detect_loop_from_start() {
+        let it = [1, 2, 1, 2, 1, 2, 1, 2].into_iter();
+        assert_eq!(detect_loop(&it), Some((0, 2)));
+    }
+
+    #[test]
+    fn test_detect_loop_ends() {
+        let it = [1, 2, 3, 4].into_iter();
+        assert_eq!(detect_loop(&it), None);
+        assert!(!is_looping(&it));
+    }
+
+    #[test]
+    fn test_detect_cycle() {
+        assert_eq!(detect_cycle(&0, rho), (3, 3));
+        assert_eq!(detect_cycle(&4, rho), (0, 3));
+        assert_eq!(detect_cycle(&7, |x| *x), (0, 1));
+    }
+
+    #[test]
+    fn test_nth_state_with_cycle() {
+        assert_eq!(nth_state_with_cycle(&0, rho, 2), 2);
+        assert_eq!(nth_state_with_cycle(&0, rho, 6), 3);
+        assert_eq!(nth_state_with_cycle(&0, rho, 1_000_000_000_000), 4);
+    }
+
+    #[test]
+    fn test_nth_state_with_cycle_robot() {
+        // A robot wrapping around a 11x7 room, as in day 14.
+        let step = |&(x, y): &(i64, i64)| ((x + 2).rem_euclid(11), (y - 3).rem_euclid(7));
+        let n = 1_000_000_000_000;
+        let expected = (
+            (2 + 2 * n as i64).rem_euclid(11),
+            (4 - 3 * n as i64).rem_euclid(7),
+        );
+        assert_eq!(nth_state_with_cycle(&(2, 4), step, n), expected);
+    }
+
+    #[test]
+    fn test_index_disjoint_set() {
+        let mut sets = IndexDisjointSet::new(6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.size(2), 4);
+        assert_eq!(sets.size(5), 1);
+        assert_eq!(sets.component_count(), 3);
+        assert_eq!(sets.components(), vec![vec![0, 1, 2, 3], vec![4], vec![5]]);
+    }
+
+    #[test]
+    fn test_disjoint_set() {
+        let mut sets = DisjointSet::new();
+        sets.union("a", "b");
+        sets.union("c", "d");
+        sets.insert("e");
+        assert!(sets.connected(&"a", &"b"));
+        assert!(!sets.connected(&"a", &"c"));
+        assert!(!sets.connected(&"a", &"z"));
+        assert_eq!(sets.find(&"b"), sets.find(&"a"));
+        assert_eq!(sets.find(&"z"), None);
+        assert_eq!(sets.size(&"d"), 2);
+        assert_eq!(sets.size(&"z"), 0);
+
+        sets.union("b", "d");
+        assert_eq!(sets.len(), 5);
+        assert_eq!(sets.component_count(), 2);
+        assert_eq!(sets.components(), vec![vec!["a", "b", "c", "d"], vec!["e"]]);
+    }
 }
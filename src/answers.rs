@@ -0,0 +1,153 @@
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    error::{numbered_lines, ParseError},
+    input::{day_dir, DEFAULT_INPUT},
+};
+
+/// The expected answers for one input of one day.
+///
+/// Stored next to the input as lines of the form `part1: <answer>`,
+/// in `answers` for the day's own input
+/// and in `<name>.answers` for any other named input.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut answers = Answers::default();
+        for (n, l) in numbered_lines(s) {
+            if l.trim().is_empty() {
+                continue;
+            }
+            let (key, value) = l.split_once(':').ok_or_else(|| {
+                ParseError::invalid("expected a line like part1: 123", l).at_line(n)
+            })?;
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                _ => {
+                    return Err(ParseError::invalid("expected part1 or part2", key)
+                        .within(l, key)
+                        .at_line(n))
+                }
+            };
+            answers.set(part, value.trim().to_owned());
+        }
+        Ok(answers)
+    }
+
+    pub fn get(&self, part: u32) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u32, answer: String) {
+        match part {
+            1 => self.part1 = Some(answer),
+            2 => self.part2 = Some(answer),
+            _ => panic!("no such part {}", part),
+        }
+    }
+
+    /// Reads the answers for a named input,
+    /// returning empty answers if none have been recorded yet.
+    pub fn load(data_dir: &Path, day: u32, input_name: &str) -> io::Result<Self> {
+        let path = answers_path(data_dir, day, input_name);
+        match fs::read_to_string(&path) {
+            Ok(s) => Answers::parse(&s).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {}", path.display(), e),
+                )
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, data_dir: &Path, day: u32, input_name: &str) -> io::Result<()> {
+        fs::write(answers_path(data_dir, day, input_name), self.to_string())
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(a) = &self.part1 {
+            writeln!(f, "part1: {}", a)?;
+        }
+        if let Some(a) = &self.part2 {
+            writeln!(f, "part2: {}", a)?;
+        }
+        Ok(())
+    }
+}
+
+/// The path of the answers file for a named input, e.g. `data/day17/answers`.
+pub fn answers_path(data_dir: &Path, day: u32, input_name: &str) -> PathBuf {
+    day_dir(data_dir, day).join(answers_file_name(input_name))
+}
+
+fn answers_file_name(input_name: &str) -> String {
+    if input_name == DEFAULT_INPUT {
+        "answers".to_owned()
+    } else {
+        format!("{}.answers", input_name)
+    }
+}
+
+/// Whether a file in a day's data directory holds answers rather than an input.
+pub fn is_answers_file(file_name: &str) -> bool {
+    file_name == "answers" || file_name.ends_with(".answers")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_round_trip() {
+        let s = "part1: 4,6,3,5\npart2: 117440\n";
+        let answers = Answers::parse(s).unwrap();
+        assert_eq!(answers.get(1), Some("4,6,3,5"));
+        assert_eq!(answers.get(2), Some("117440"));
+        assert_eq!(answers.to_string(), s);
+    }
+
+    #[test]
+    fn test_parse_missing_part() {
+        let answers = Answers::parse("part1: 11\n").unwrap();
+        assert_eq!(answers.get(1), Some("11"));
+        assert_eq!(answers.get(2), None);
+    }
+
+    #[test]
+    fn test_parse_bad_key() {
+        let err = Answers::parse("part1: 11\npart3: 12\n").unwrap_err();
+        assert_eq!(err.line, Some(2));
+        assert_eq!(err.column, Some(1));
+    }
+
+    #[test]
+    fn test_answers_path() {
+        let dir = Path::new("data");
+        assert_eq!(
+            answers_path(dir, 17, "input"),
+            Path::new("data/day17/answers")
+        );
+        assert_eq!(
+            answers_path(dir, 17, "example1"),
+            Path::new("data/day17/example1.answers")
+        );
+    }
+}
@@ -0,0 +1,245 @@
+use std::{
+    fmt::Write,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::{Error, SolveError},
+    solution::Solution,
+};
+
+/// How many times to run each stage of a solver when benchmarking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Untimed runs made first, to warm caches and the allocator.
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: 10,
+        }
+    }
+}
+
+/// Summary statistics over the timings of repeated runs, in microseconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    /// Sample standard deviation, or 0 if there was only one sample.
+    pub stddev: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarise");
+
+        let mut micros = samples
+            .iter()
+            .map(|d| d.as_secs_f64() * 1e6)
+            .collect::<Vec<_>>();
+        micros.sort_by(f64::total_cmp);
+
+        let n = micros.len();
+        let median = if n % 2 == 0 {
+            (micros[n / 2 - 1] + micros[n / 2]) / 2.0
+        } else {
+            micros[n / 2]
+        };
+        let mean = micros.iter().sum::<f64>() / n as f64;
+        let stddev = if n > 1 {
+            let variance = micros.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+            variance.sqrt()
+        } else {
+            0.0
+        };
+
+        Stats {
+            min: micros[0],
+            median,
+            mean,
+            stddev,
+        }
+    }
+
+    fn write_json(&self, out: &mut String) {
+        write!(
+            out,
+            "{{\"min_us\": {:.3}, \"median_us\": {:.3}, \"mean_us\": {:.3}, \"stddev_us\": {:.3}}}",
+            self.min, self.median, self.mean, self.stddev
+        )
+        .unwrap();
+    }
+}
+
+/// Timings for one part of one day,
+/// with parsing and solving measured separately.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PartBench {
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+pub fn bench_part1<S: Solution>(input: &str, config: &BenchConfig) -> Result<PartBench, Error> {
+    bench_part::<S, _>(input, config, S::part1)
+}
+
+pub fn bench_part2<S: Solution>(input: &str, config: &BenchConfig) -> Result<PartBench, Error> {
+    bench_part::<S, _>(input, config, S::part2)
+}
+
+fn bench_part<S: Solution, A>(
+    input: &str,
+    config: &BenchConfig,
+    part: fn(&S::Input) -> Result<A, SolveError>,
+) -> Result<PartBench, Error> {
+    // Run everything once up front so that failures are reported
+    // rather than timed.
+    let parsed = S::parse(input)?;
+    part(&parsed)?;
+
+    Ok(PartBench {
+        parse: measure(config, || S::parse(black_box(input))),
+        solve: measure(config, || part(black_box(&parsed))),
+    })
+}
+
+/// Times `f` over the configured number of iterations, after warming up.
+pub fn measure<T>(config: &BenchConfig, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+    let samples = (0..config.iterations.max(1))
+        .map(|_| {
+            let start_time = Instant::now();
+            black_box(f());
+            start_time.elapsed()
+        })
+        .collect::<Vec<_>>();
+    Stats::from_samples(&samples)
+}
+
+/// The benchmark result for one part of one day against one input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchRecord {
+    pub day: u32,
+    pub part: u32,
+    pub input: String,
+    pub bench: PartBench,
+}
+
+/// Formats benchmark results as JSON, for comparing runs across commits.
+pub fn to_json(config: &BenchConfig, records: &[BenchRecord]) -> String {
+    let mut out = String::new();
+    writeln!(out, "{{").unwrap();
+    writeln!(out, "  \"warmup\": {},", config.warmup).unwrap();
+    writeln!(out, "  \"iterations\": {},", config.iterations).unwrap();
+    write!(out, "  \"results\": [").unwrap();
+    for (i, r) in records.iter().enumerate() {
+        let sep = if i == 0 { "" } else { "," };
+        write!(
+            out,
+            "{}\n    {{\"day\": {}, \"part\": {}, \"input\": ",
+            sep, r.day, r.part
+        )
+        .unwrap();
+        write_json_string(&mut out, &r.input);
+        out.push_str(", \"parse\": ");
+        r.bench.parse.write_json(&mut out);
+        out.push_str(", \"solve\": ");
+        r.bench.solve.write_json(&mut out);
+        out.push('}');
+    }
+    if !records.is_empty() {
+        out.push_str("\n  ");
+    }
+    writeln!(out, "]").unwrap();
+    writeln!(out, "}}").unwrap();
+    out
+}
+
+fn write_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(us: &[u64]) -> Vec<Duration> {
+        us.iter().map(|&us| Duration::from_micros(us)).collect()
+    }
+
+    #[test]
+    fn test_stats_odd() {
+        let stats = Stats::from_samples(&micros(&[5, 1, 3]));
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.median, 3.0);
+        assert_eq!(stats.mean, 3.0);
+        assert_eq!(stats.stddev, 2.0);
+    }
+
+    #[test]
+    fn test_stats_even() {
+        let stats = Stats::from_samples(&micros(&[4, 1, 2, 3]));
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.median, 2.5);
+        assert_eq!(stats.mean, 2.5);
+    }
+
+    #[test]
+    fn test_stats_single() {
+        let stats = Stats::from_samples(&micros(&[7]));
+        assert_eq!(stats.median, 7.0);
+        assert_eq!(stats.stddev, 0.0);
+    }
+
+    #[test]
+    fn test_to_json() {
+        let stats = Stats::from_samples(&micros(&[2]));
+        let records = [BenchRecord {
+            day: 17,
+            part: 1,
+            input: "ex\"1".to_owned(),
+            bench: PartBench {
+                parse: stats,
+                solve: stats,
+            },
+        }];
+        let config = BenchConfig {
+            warmup: 0,
+            iterations: 1,
+        };
+        let stats_json =
+            "{\"min_us\": 2.000, \"median_us\": 2.000, \"mean_us\": 2.000, \"stddev_us\": 0.000}";
+        let expected = format!(
+            "\
+{{
+  \"warmup\": 0,
+  \"iterations\": 1,
+  \"results\": [
+    {{\"day\": 17, \"part\": 1, \"input\": \"ex\\\"1\", \"parse\": {0}, \"solve\": {0}}}
+  ]
+}}
+",
+            stats_json
+        );
+        assert_eq!(to_json(&config, &records), expected);
+    }
+}
@@ -0,0 +1,561 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc24::{
+    answers::{answers_path, Answers},
+    bench::{to_json, BenchConfig, BenchRecord},
+    days::{find_solver, solvers_for_day, Solver, SOLVERS},
+    error::{Error, ParseError},
+    input::{default_data_dir, input_names, input_path, read_input, DEFAULT_INPUT, STDIN_PATH},
+};
+
+const USAGE: &str = "\
+Usage:
+    aoc run <day> [part] [options]
+    aoc run all [options]
+    aoc verify [day] [part] [options] [--record]
+    aoc bench [day] [part] [options] [--iterations N] [--warmup N] [--json <path>]
+    aoc list
+
+Options:
+    --input <path>      Read the input from <path>, or from stdin if <path> is -
+    --variant <name>    Use data/dayNN/<name> instead of data/dayNN/input
+    --all-inputs        Run against every input in data/dayNN
+    --data-dir <dir>    Look for inputs in <dir> instead of the repo's data directory
+    --record            Save the current answers as the expected answers
+    --iterations <n>    Time each stage <n> times (default 10)
+    --warmup <n>        Run each stage <n> times before timing it (default 3)
+    --json <path>       Also write the benchmark results to <path> as JSON";
+
+/// Which input or inputs each day is run against.
+enum InputChoice {
+    /// The day's own puzzle input, `data/dayNN/input`.
+    Default,
+    /// A named input in the day's data directory, e.g. `example1`.
+    Variant(String),
+    /// Every input in the day's data directory.
+    AllVariants,
+    /// The same file for every day.
+    Path(PathBuf),
+    /// Stdin, read once up front and shared by every day.
+    Stdin(String),
+}
+
+struct Options {
+    data_dir: PathBuf,
+    input: InputChoice,
+    record: bool,
+    bench: BenchConfig,
+    /// Where `bench` writes its results as JSON.
+    json: Option<PathBuf>,
+}
+
+/// A single input to run a solver against.
+struct NamedInput {
+    /// The input's name within the day's data directory,
+    /// or `None` if it came from elsewhere.
+    name: Option<String>,
+    /// Shown next to the answer, unless this is the day's default input.
+    label: Option<String>,
+    path: PathBuf,
+}
+
+fn time_solver(solver: &Solver, input_str: &str) -> (Result<String, Error>, Duration) {
+    let start_time = Instant::now();
+    let answer = (solver.run)(input_str);
+    (answer, start_time.elapsed())
+}
+
+/// Runs a single solver against a single input and prints its answer,
+/// returning whether it succeeded.
+fn run_solver(solver: &Solver, input: &NamedInput, input_str: &str) -> bool {
+    let name = match &input.label {
+        Some(label) => format!("Day {:02} part {} [{}]", solver.day, solver.part, label),
+        None => format!("Day {:02} part {}", solver.day, solver.part),
+    };
+
+    let (answer, elapsed) = time_solver(solver, input_str);
+    let answer = match answer {
+        Ok(answer) => answer,
+        Err(e) => {
+            eprintln!("{}: {}: {}", name, input.path.display(), e);
+            if let Error::Parse(e) = &e {
+                print_excerpt(input_str, e);
+            }
+            return false;
+        }
+    };
+
+    println!("{}: {} ({}ms)", name, answer, elapsed.as_millis());
+    true
+}
+
+/// Prints the input line that a parse error points at,
+/// underlining the offending text if its column is known.
+fn print_excerpt(input: &str, e: &ParseError) {
+    let Some(line_num) = e.line else {
+        return;
+    };
+    let Some(line) = input.lines().nth(line_num - 1) else {
+        return;
+    };
+    let gutter = line_num.to_string().len();
+    eprintln!("{:>w$} | {}", line_num, line, w = gutter);
+    if let Some(column) = e.column {
+        let width = e.text.as_ref().map_or(1, |t| t.chars().count().max(1));
+        eprintln!(
+            "{:>w$} | {}{}",
+            "",
+            " ".repeat(column - 1),
+            "^".repeat(width),
+            w = gutter
+        );
+    }
+}
+
+fn list() {
+    for solver in SOLVERS {
+        println!("Day {:02} part {}", solver.day, solver.part);
+    }
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let (args, options) = parse_options(args, &[])?;
+    let solvers = select_solvers(&args)?;
+
+    let mut run_failures = 0;
+    let input_failures = for_each_input(&solvers, &options, |solver, input, input_str| {
+        if !run_solver(solver, input, input_str) {
+            run_failures += 1;
+        }
+    });
+
+    match input_failures + run_failures {
+        0 => Ok(()),
+        1 => Err("1 run failed".to_string()),
+        n => Err(format!("{} runs failed", n)),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Pass,
+    Fail,
+    /// There is no expected answer to compare against.
+    New,
+    Error,
+}
+
+impl Status {
+    fn label(self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::New => "new",
+            Status::Error => "ERROR",
+        }
+    }
+}
+
+/// One line of the table printed by `verify`.
+struct Check {
+    day: u32,
+    part: u32,
+    input: String,
+    status: Status,
+    elapsed: Duration,
+    detail: String,
+}
+
+fn verify(args: &[String]) -> Result<(), String> {
+    let (args, options) = parse_options(args, &["--record"])?;
+    if matches!(options.input, InputChoice::Path(_) | InputChoice::Stdin(_)) {
+        return Err(
+            "verify needs inputs from the data directory, use --variant instead".to_string(),
+        );
+    }
+    let solvers = if args.is_empty() {
+        SOLVERS.iter().collect()
+    } else {
+        select_solvers(&args)?
+    };
+
+    // Answers are loaded once per input and, when recording,
+    // updated in place and written back at the end.
+    let mut answers = BTreeMap::<(u32, String), Result<Answers, String>>::new();
+    let mut checks = Vec::new();
+    let input_failures = for_each_input(&solvers, &options, |solver, input, input_str| {
+        let name = input.name.clone().unwrap_or_default();
+        let expected = answers
+            .entry((solver.day, name.clone()))
+            .or_insert_with(|| {
+                Answers::load(&options.data_dir, solver.day, &name).map_err(|e| e.to_string())
+            });
+
+        let (answer, elapsed) = time_solver(solver, input_str);
+        let (status, detail) = match (&answer, &expected) {
+            (Err(e), _) => (Status::Error, e.to_string()),
+            (_, Err(e)) => (Status::Error, e.clone()),
+            (Ok(answer), Ok(expected)) => match expected.get(solver.part) {
+                Some(e) if e == answer => (Status::Pass, answer.clone()),
+                Some(e) => (Status::Fail, format!("{} (expected {})", answer, e)),
+                None => (Status::New, answer.clone()),
+            },
+        };
+
+        if let (true, Ok(answer), Ok(expected)) = (options.record, answer, expected) {
+            expected.set(solver.part, answer);
+        }
+
+        checks.push(Check {
+            day: solver.day,
+            part: solver.part,
+            input: name,
+            status,
+            elapsed,
+            detail,
+        });
+    });
+
+    print_checks(&checks);
+
+    if options.record {
+        let mut saved = 0;
+        for ((day, name), answers) in &answers {
+            let Ok(answers) = answers else {
+                continue;
+            };
+            answers.save(&options.data_dir, *day, name).map_err(|e| {
+                let path = answers_path(&options.data_dir, *day, name);
+                format!("failed to write {}: {}", path.display(), e)
+            })?;
+            saved += 1;
+        }
+        println!("Recorded answers for {} inputs", saved);
+    }
+
+    let count = |status| checks.iter().filter(|c| c.status == status).count();
+    let failures = count(Status::Error)
+        + input_failures
+        + if options.record {
+            0
+        } else {
+            count(Status::Fail)
+        };
+    println!(
+        "{} passed, {} failed, {} new, {} errors",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::New),
+        count(Status::Error) + input_failures
+    );
+    match failures {
+        0 => Ok(()),
+        1 => Err("1 check failed".to_string()),
+        n => Err(format!("{} checks failed", n)),
+    }
+}
+
+fn print_checks(checks: &[Check]) {
+    let input_width = checks
+        .iter()
+        .map(|c| c.input.len())
+        .chain(["Input".len()])
+        .max()
+        .unwrap_or_default();
+    println!(
+        "Day  Part  {:<w$}  Status  {:>8}  Answer",
+        "Input",
+        "Time",
+        w = input_width
+    );
+    for c in checks {
+        println!(
+            "{:>3}  {:>4}  {:<w$}  {:<6}  {:>6}ms  {}",
+            format!("{:02}", c.day),
+            c.part,
+            c.input,
+            c.status.label(),
+            c.elapsed.as_millis(),
+            c.detail,
+            w = input_width
+        );
+    }
+}
+
+fn bench(args: &[String]) -> Result<(), String> {
+    let (args, options) = parse_options(args, &["--iterations", "--warmup", "--json"])?;
+    let solvers = if args.is_empty() {
+        SOLVERS.iter().collect()
+    } else {
+        select_solvers(&args)?
+    };
+
+    println!(
+        "Day  Part  Stage  {:>10}  {:>10}  {:>10}  {:>10}  Input",
+        "Min µs", "Median µs", "Mean µs", "Stddev µs"
+    );
+    let mut records = Vec::new();
+    let mut bench_failures = 0;
+    let input_failures = for_each_input(&solvers, &options, |solver, input, input_str| {
+        let input_name = input
+            .name
+            .clone()
+            .unwrap_or_else(|| input.path.display().to_string());
+        let bench = match (solver.bench)(input_str, &options.bench) {
+            Ok(bench) => bench,
+            Err(e) => {
+                eprintln!(
+                    "Day {:02} part {}: {}: {}",
+                    solver.day,
+                    solver.part,
+                    input.path.display(),
+                    e
+                );
+                bench_failures += 1;
+                return;
+            }
+        };
+        for (stage, stats) in [("parse", bench.parse), ("solve", bench.solve)] {
+            println!(
+                "{:>3}  {:>4}  {:<5}  {:>10.1}  {:>10.1}  {:>10.1}  {:>10.1}  {}",
+                format!("{:02}", solver.day),
+                solver.part,
+                stage,
+                stats.min,
+                stats.median,
+                stats.mean,
+                stats.stddev,
+                input_name
+            );
+        }
+        records.push(BenchRecord {
+            day: solver.day,
+            part: solver.part,
+            input: input_name,
+            bench,
+        });
+    });
+
+    if let Some(path) = &options.json {
+        fs::write(path, to_json(&options.bench, &records))
+            .map_err(|e| format!("failed to write {}: {}", path.display(), e))?;
+    }
+
+    match input_failures + bench_failures {
+        0 => Ok(()),
+        1 => Err("1 benchmark failed".to_string()),
+        n => Err(format!("{} benchmarks failed", n)),
+    }
+}
+
+/// Picks the solvers named by `[day] [part]` or `all`.
+fn select_solvers(args: &[&str]) -> Result<Vec<&'static Solver>, String> {
+    match args {
+        ["all"] => Ok(SOLVERS.iter().collect()),
+        [day] => {
+            let day = parse_number(day, "day")?;
+            let solvers = solvers_for_day(day).collect::<Vec<_>>();
+            if solvers.is_empty() {
+                return Err(format!("no solvers for day {}", day));
+            }
+            Ok(solvers)
+        }
+        [day, part] => {
+            let day = parse_number(day, "day")?;
+            let part = parse_number(part, "part")?;
+            let solver = find_solver(day, part)
+                .ok_or_else(|| format!("no solver for day {} part {}", day, part))?;
+            Ok(vec![solver])
+        }
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+/// Calls `f` for every solver and each of its selected inputs,
+/// even after a failure, passing along the contents of the input.
+///
+/// Returns the number of inputs that couldn't be found or read.
+fn for_each_input(
+    solvers: &[&Solver],
+    options: &Options,
+    mut f: impl FnMut(&Solver, &NamedInput, &str),
+) -> usize {
+    let mut failures = 0;
+    for solver in solvers {
+        let inputs = match select_inputs(solver.day, options) {
+            Ok(inputs) => inputs,
+            Err(e) => {
+                eprintln!("Day {:02} part {}: {}", solver.day, solver.part, e);
+                failures += 1;
+                continue;
+            }
+        };
+        for input in &inputs {
+            let input_str = match &options.input {
+                InputChoice::Stdin(s) => s.clone(),
+                _ => match read_input(&input.path) {
+                    Ok(s) => s,
+                    Err(e) => {
+                        eprintln!(
+                            "Day {:02} part {}: failed to read {}: {}",
+                            solver.day,
+                            solver.part,
+                            input.path.display(),
+                            e
+                        );
+                        failures += 1;
+                        continue;
+                    }
+                },
+            };
+            f(solver, input, &input_str);
+        }
+    }
+    failures
+}
+
+fn select_inputs(day: u32, options: &Options) -> Result<Vec<NamedInput>, String> {
+    let named = |name: &str| NamedInput {
+        name: Some(name.to_string()),
+        label: (name != DEFAULT_INPUT).then(|| name.to_string()),
+        path: input_path(&options.data_dir, day, name),
+    };
+    match &options.input {
+        InputChoice::Default => Ok(vec![named(DEFAULT_INPUT)]),
+        InputChoice::Variant(name) => Ok(vec![named(name)]),
+        InputChoice::AllVariants => {
+            let names = input_names(&options.data_dir, day)
+                .map_err(|e| format!("failed to list inputs: {}", e))?;
+            Ok(names.iter().map(|name| named(name)).collect())
+        }
+        InputChoice::Path(path) => Ok(vec![NamedInput {
+            name: None,
+            label: Some(path.display().to_string()),
+            path: path.clone(),
+        }]),
+        InputChoice::Stdin(_) => Ok(vec![NamedInput {
+            name: None,
+            label: Some("stdin".to_string()),
+            path: PathBuf::from("<stdin>"),
+        }]),
+    }
+}
+
+/// Splits the options out of `args`,
+/// returning them along with the remaining positional arguments.
+///
+/// The input options are accepted by every command,
+/// while `extra` lists the options specific to the calling command.
+fn parse_options<'a>(
+    args: &'a [String],
+    extra: &[&str],
+) -> Result<(Vec<&'a str>, Options), String> {
+    let mut positional = Vec::new();
+    let mut data_dir = None;
+    let mut input = None;
+    let mut record = false;
+    let mut bench = BenchConfig::default();
+    let mut json = None;
+
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        let mut value = || {
+            it.next()
+                .ok_or_else(|| format!("missing value for {}", arg))
+        };
+        let is_common = matches!(
+            arg.as_str(),
+            "--input" | "--variant" | "--all-inputs" | "--data-dir"
+        );
+        if arg.starts_with("--") && !is_common && !extra.contains(&arg.as_str()) {
+            return Err(format!("unknown option {}", arg));
+        }
+        let choice = match arg.as_str() {
+            "--input" => {
+                let path = value()?;
+                if path == STDIN_PATH {
+                    let s = read_input(Path::new(STDIN_PATH))
+                        .map_err(|e| format!("failed to read stdin: {}", e))?;
+                    InputChoice::Stdin(s)
+                } else {
+                    InputChoice::Path(PathBuf::from(path))
+                }
+            }
+            "--variant" => InputChoice::Variant(value()?.clone()),
+            "--all-inputs" => InputChoice::AllVariants,
+            "--data-dir" => {
+                data_dir = Some(PathBuf::from(value()?));
+                continue;
+            }
+            "--record" => {
+                record = true;
+                continue;
+            }
+            "--iterations" => {
+                bench.iterations = parse_count(value()?, "iterations")?;
+                continue;
+            }
+            "--warmup" => {
+                bench.warmup = parse_count(value()?, "warmup")?;
+                continue;
+            }
+            "--json" => {
+                json = Some(PathBuf::from(value()?));
+                continue;
+            }
+            _ => {
+                positional.push(arg.as_str());
+                continue;
+            }
+        };
+        if input.replace(choice).is_some() {
+            return Err("only one of --input, --variant and --all-inputs may be given".to_string());
+        }
+    }
+
+    let options = Options {
+        data_dir: data_dir.unwrap_or_else(default_data_dir),
+        input: input.unwrap_or(InputChoice::Default),
+        record,
+        bench,
+        json,
+    };
+    Ok((positional, options))
+}
+
+fn parse_number(s: &str, what: &str) -> Result<u32, String> {
+    s.parse().map_err(|_| format!("invalid {}: {}", what, s))
+}
+
+fn parse_count(s: &str, what: &str) -> Result<usize, String> {
+    s.parse().map_err(|_| format!("invalid {}: {}", what, s))
+}
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    let result = match args.split_first() {
+        Some((cmd, rest)) if cmd == "run" => run(rest),
+        Some((cmd, rest)) if cmd == "verify" => verify(rest),
+        Some((cmd, rest)) if cmd == "bench" => bench(rest),
+        Some((cmd, [])) if cmd == "list" => {
+            list();
+            Ok(())
+        }
+        _ => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
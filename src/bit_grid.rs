@@ -0,0 +1,397 @@
+use std::fmt::Display;
+
+use crate::{
+    grid::Grid,
+    position::{pos, Position},
+};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A grid of booleans packed one bit per cell,
+/// for visited sets and occupancy maps over a fixed area.
+///
+/// Each row starts on a fresh word, so whole rows can be shifted and
+/// combined a word at a time, as in cellular-automaton style updates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// A grid with every cell unset.
+    pub fn new(width: usize, height: usize) -> Self {
+        let words_per_row = width.div_ceil(WORD_BITS);
+        Self {
+            width,
+            height,
+            words_per_row,
+            words: vec![0; words_per_row * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn bit_index(&self, x: usize, y: usize) -> Option<(usize, u64)> {
+        (x < self.width && y < self.height).then(|| {
+            let word = y * self.words_per_row + x / WORD_BITS;
+            (word, 1 << (x % WORD_BITS))
+        })
+    }
+
+    fn pos_bit_index(&self, pos: &Position) -> Option<(usize, u64)> {
+        let x = usize::try_from(pos.x).ok()?;
+        let y = usize::try_from(pos.y).ok()?;
+        self.bit_index(x, y)
+    }
+
+    pub fn is_in_bounds(&self, pos: &Position) -> bool {
+        self.pos_bit_index(pos).is_some()
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        let (word, bit) = self.bit_index(x, y).unwrap();
+        self.words[word] & bit != 0
+    }
+
+    pub fn get_pos(&self, pos: &Position) -> bool {
+        self.try_get_pos(pos).unwrap()
+    }
+
+    pub fn try_get_pos(&self, pos: &Position) -> Option<bool> {
+        self.pos_bit_index(pos)
+            .map(|(word, bit)| self.words[word] & bit != 0)
+    }
+
+    /// Whether `pos` is set, treating everything outside the grid as unset.
+    pub fn contains(&self, pos: &Position) -> bool {
+        self.try_get_pos(pos).unwrap_or(false)
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, val: bool) {
+        let (word, bit) = self.bit_index(x, y).unwrap();
+        self.write(word, bit, val);
+    }
+
+    pub fn set_pos(&mut self, pos: &Position, val: bool) {
+        let (word, bit) = self.pos_bit_index(pos).unwrap();
+        self.write(word, bit, val);
+    }
+
+    fn write(&mut self, word: usize, bit: u64, val: bool) {
+        if val {
+            self.words[word] |= bit;
+        } else {
+            self.words[word] &= !bit;
+        }
+    }
+
+    /// Sets `pos`, returning whether it was unset before, like `HashSet::insert`.
+    pub fn insert(&mut self, pos: Position) -> bool {
+        let (word, bit) = self.pos_bit_index(&pos).unwrap();
+        let was_unset = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        was_unset
+    }
+
+    /// The number of set cells.
+    pub fn count(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// The positions of the set cells, row by row.
+    pub fn iter_ones(&self) -> impl Iterator<Item = Position> + '_ {
+        self.words.iter().enumerate().flat_map(move |(i, &word)| {
+            let y = i / self.words_per_row;
+            let x0 = (i % self.words_per_row) * WORD_BITS;
+            let mut rest = word;
+            std::iter::from_fn(move || {
+                (rest != 0).then(|| {
+                    let bit = rest.trailing_zeros() as usize;
+                    rest &= rest - 1;
+                    pos((x0 + bit) as i64, y as i64)
+                })
+            })
+        })
+    }
+
+    fn zip_words<F>(&mut self, other: &BitGrid, f: F)
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "bit grids must be the same size"
+        );
+        for (a, &b) in self.words.iter_mut().zip(&other.words) {
+            *a = f(*a, b);
+        }
+    }
+
+    /// Sets every cell that is set in `other`.
+    pub fn union_with(&mut self, other: &BitGrid) {
+        self.zip_words(other, |a, b| a | b);
+    }
+
+    /// Unsets every cell that is unset in `other`, i.e. masks by `other`.
+    pub fn intersect_with(&mut self, other: &BitGrid) {
+        self.zip_words(other, |a, b| a & b);
+    }
+
+    /// Unsets every cell that is set in `other`.
+    pub fn difference_with(&mut self, other: &BitGrid) {
+        self.zip_words(other, |a, b| a & !b);
+    }
+
+    pub fn union(&self, other: &BitGrid) -> BitGrid {
+        let mut result = self.clone();
+        result.union_with(other);
+        result
+    }
+
+    pub fn intersection(&self, other: &BitGrid) -> BitGrid {
+        let mut result = self.clone();
+        result.intersect_with(other);
+        result
+    }
+
+    pub fn difference(&self, other: &BitGrid) -> BitGrid {
+        let mut result = self.clone();
+        result.difference_with(other);
+        result
+    }
+
+    /// The grid with every cell moved `dx` right and `dy` down.
+    /// Cells moved past an edge are dropped and the vacated cells are unset.
+    pub fn shifted(&self, dx: i64, dy: i64) -> BitGrid {
+        let mut result = BitGrid::new(self.width, self.height);
+        for y in 0..self.height {
+            let Some(from_y) = y.checked_add_signed(-dy as isize) else {
+                continue;
+            };
+            if from_y >= self.height {
+                continue;
+            }
+            let from = &self.words[from_y * self.words_per_row..][..self.words_per_row];
+            let to = &mut result.words[y * self.words_per_row..][..self.words_per_row];
+            shift_row(from, to, dx);
+        }
+        result.mask_row_ends();
+        result
+    }
+
+    /// The set cells along with all their orthogonal neighbours.
+    pub fn dilate(&self) -> BitGrid {
+        let mut result = self.clone();
+        for (dx, dy) in [(0, -1), (1, 0), (0, 1), (-1, 0)] {
+            result.union_with(&self.shifted(dx, dy));
+        }
+        result
+    }
+
+    // Clears the padding bits past the end of each row,
+    // which shifting right can fill.
+    fn mask_row_ends(&mut self) {
+        let used = self.width % WORD_BITS;
+        if used == 0 {
+            return;
+        }
+        let mask = (1 << used) - 1;
+        for row in self.words.chunks_mut(self.words_per_row) {
+            *row.last_mut().unwrap() &= mask;
+        }
+    }
+
+    /// A copy as a `Grid<bool>`, e.g. for rendering.
+    pub fn to_grid(&self) -> Grid<bool> {
+        Grid::from_fn(self.width, self.height, |x, y| self.get(x, y))
+    }
+}
+
+// Moves the bits of one row `dx` towards higher x (or lower, if negative).
+fn shift_row(from: &[u64], to: &mut [u64], dx: i64) {
+    let n = from.len() as i64;
+    let words = dx.div_euclid(WORD_BITS as i64);
+    let bits = dx.rem_euclid(WORD_BITS as i64) as u32;
+    // Bit x of the result comes from bit x - dx, which straddles
+    // words i - words and i - words - 1 of the source.
+    let source = |i: i64| {
+        if (0..n).contains(&i) {
+            from[i as usize]
+        } else {
+            0
+        }
+    };
+    for (i, word) in to.iter_mut().enumerate() {
+        let i = i as i64;
+        let low = source(i - words);
+        *word = if bits == 0 {
+            low
+        } else {
+            (low << bits) | (source(i - words - 1) >> (WORD_BITS as u32 - bits))
+        };
+    }
+}
+
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.to_grid().render(|&b| if b { '#' } else { '.' }).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bits(s: &str) -> BitGrid {
+        Grid::try_from_str(s).unwrap().map_to_bits(|&c| c == '#')
+    }
+
+    #[test]
+    fn test_get_set() {
+        let mut g = BitGrid::new(70, 3);
+        assert!(!g.get(69, 2));
+        g.set(69, 2, true);
+        g.set(0, 1, true);
+        assert!(g.get(69, 2));
+        assert!(g.get_pos(&pos(0, 1)));
+        assert_eq!(g.try_get_pos(&pos(70, 0)), None);
+        assert_eq!(g.try_get_pos(&pos(-1, 0)), None);
+        assert!(!g.contains(&pos(0, 3)));
+        assert_eq!(g.count(), 2);
+
+        g.set_pos(&pos(69, 2), false);
+        assert!(!g.get(69, 2));
+        assert_eq!(g.count(), 1);
+
+        g.clear();
+        assert!(g.is_empty());
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut g = BitGrid::new(3, 3);
+        assert!(g.insert(pos(1, 2)));
+        assert!(!g.insert(pos(1, 2)));
+        assert!(g.contains(&pos(1, 2)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_get_out_of_bounds() {
+        BitGrid::new(3, 3).get(3, 0);
+    }
+
+    #[test]
+    fn test_map_to_bits() {
+        let g = bits("#..\n.##\n");
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(
+            g.iter_ones().collect::<Vec<_>>(),
+            vec![pos(0, 0), pos(1, 1), pos(2, 1)]
+        );
+        assert_eq!(g.to_string(), "#..\n.##\n");
+        assert_eq!(
+            g.to_grid(),
+            Grid::from_vecs(&[vec![true, false, false], vec![false, true, true]])
+        );
+    }
+
+    #[test]
+    fn test_set_ops() {
+        let a = bits("##.\n#..\n");
+        let b = bits(".##\n..#\n");
+        assert_eq!(a.union(&b), bits("###\n#.#\n"));
+        assert_eq!(a.intersection(&b), bits(".#.\n...\n"));
+        assert_eq!(a.difference(&b), bits("#..\n#..\n"));
+        assert_eq!(a.union(&b).count(), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "bit grids must be the same size")]
+    fn test_set_ops_size_mismatch() {
+        BitGrid::new(3, 2).union(&BitGrid::new(2, 3));
+    }
+
+    #[test]
+    fn test_shifted() {
+        let g = bits("#.#\n.#.\n..#\n");
+        assert_eq!(g.shifted(1, 0), bits(".#.\n..#\n...\n"));
+        assert_eq!(g.shifted(-1, 0), bits(".#.\n#..\n.#.\n"));
+        assert_eq!(g.shifted(0, 1), bits("...\n#.#\n.#.\n"));
+        assert_eq!(g.shifted(0, -1), bits(".#.\n..#\n...\n"));
+        assert_eq!(g.shifted(-1, -1), bits("#..\n.#.\n...\n"));
+        assert_eq!(g.shifted(3, 0), BitGrid::new(3, 3));
+        assert_eq!(g.shifted(0, -5), BitGrid::new(3, 3));
+        assert_eq!(g.shifted(0, 0), g);
+    }
+
+    #[test]
+    fn test_shifted_across_words() {
+        // Rows of 130 bits span three words.
+        let mut g = BitGrid::new(130, 2);
+        for x in [0, 63, 64, 127, 129] {
+            g.set(x, 0, true);
+        }
+        let xs = |g: &BitGrid| g.iter_ones().map(|p| (p.x, p.y)).collect::<Vec<_>>();
+
+        assert_eq!(
+            xs(&g.shifted(1, 1)),
+            vec![(1, 1), (64, 1), (65, 1), (128, 1)]
+        );
+        assert_eq!(xs(&g.shifted(-64, 0)), vec![(0, 0), (63, 0), (65, 0)]);
+        assert_eq!(xs(&g.shifted(70, 0)), vec![(70, 0)]);
+
+        // Shifting there and back keeps exactly the cells that did not fall off.
+        for dx in [-129, -65, -64, -1, 1, 2, 64, 100] {
+            let expected = g
+                .iter_ones()
+                .filter(|p| (0..130).contains(&(p.x + dx)))
+                .map(|p| (p.x, p.y))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                xs(&g.shifted(dx, 0).shifted(-dx, 0)),
+                expected,
+                "dx = {}",
+                dx
+            );
+        }
+    }
+
+    #[test]
+    fn test_dilate() {
+        let free = bits("###..\n#.#.#\n....#\n");
+        let mut reached = BitGrid::new(5, 3);
+        reached.insert(pos(0, 0));
+
+        // Spread through the set cells one step at a time.
+        let mut steps = 0;
+        while !reached.contains(&pos(4, 2)) {
+            let next = reached.dilate().intersection(&free);
+            if next == reached {
+                break;
+            }
+            reached = next;
+            steps += 1;
+        }
+        assert_eq!(steps, 3);
+        assert_eq!(reached.to_string(), "###..\n#.#..\n.....\n");
+    }
+}
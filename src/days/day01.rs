@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use crate::{
+    error::{parse_field, parse_lines, ParseError, SolveError},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Input {
+    list_a: Vec<i32>,
+    list_b: Vec<i32>,
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let pairs = parse_lines(s, |l| {
+        let [a, b]: [i32; 2] = l
+            .split_ascii_whitespace()
+            .map(|x| parse_field(l, x))
+            .collect::<Result<Vec<_>, _>>()?
+            .try_into()
+            .map_err(|_| ParseError::invalid("expected two numbers", l))?;
+
+        Ok((a, b))
+    })?;
+    let (list_a, list_b) = pairs.into_iter().unzip();
+
+    Ok(Input { list_a, list_b })
+}
+
+fn solve1(input: &Input) -> u32 {
+    let mut list_a = input.list_a.clone();
+    let mut list_b = input.list_b.clone();
+
+    list_a.sort();
+    list_b.sort();
+
+    let val: u32 = list_a
+        .into_iter()
+        .zip(list_b)
+        .map(|(a, b)| a.abs_diff(b))
+        .sum();
+
+    val
+}
+
+fn solve2(input: &Input) -> i32 {
+    let freqs = {
+        let mut freqs = HashMap::new();
+        for x in &input.list_b {
+            freqs.entry(*x).and_modify(|e| *e += 1).or_insert(1);
+        }
+        freqs
+    };
+
+    let val: i32 = input
+        .list_a
+        .iter()
+        .map(|x| {
+            let freq = freqs.get(x).copied().unwrap_or(0);
+            x * freq
+        })
+        .sum();
+
+    val
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Input;
+    type Answer1 = u32;
+    type Answer2 = i32;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<u32, SolveError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: &Input) -> Result<i32, SolveError> {
+        Ok(solve2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    #[test]
+    fn test_solve1() {
+        let input = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve1(&input), 11)
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve2(&input), 31)
+    }
+}
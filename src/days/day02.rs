@@ -0,0 +1,118 @@
+use crate::{
+    error::{parse_field, parse_lines, ParseError, SolveError},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Input {
+    reports: Vec<Vec<i32>>,
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let reports = parse_lines(s, |l| {
+        l.split_ascii_whitespace()
+            .map(|n| parse_field(l, n))
+            .collect()
+    })?;
+    Ok(Input { reports })
+}
+
+fn solve1(input: &Input) -> usize {
+    input.reports.iter().filter(|x| is_safe_report(x)).count()
+}
+
+fn solve2(input: &Input) -> usize {
+    input
+        .reports
+        .iter()
+        .filter(|x| is_safe_report_with_tolerance(x))
+        .count()
+}
+
+fn is_safe_report(x: &[i32]) -> bool {
+    (all_increasing(x) || all_decreasing(x)) && differ_in_range(x)
+}
+
+fn is_safe_report_with_tolerance(x: &[i32]) -> bool {
+    if is_safe_report(x) {
+        return true;
+    }
+
+    // This is really slow but the lists are really short (<10 elems)
+    // so it doesn't matter an awful lot.
+    (0..x.len()).any(|i| {
+        let mut x2 = x.to_vec();
+        x2.remove(i);
+        is_safe_report(&x2)
+    })
+}
+
+fn differ_in_range(xs: &[i32]) -> bool {
+    xs.windows(2).all(|w| {
+        let (a, b) = (w[0], w[1]);
+        let diff = a.abs_diff(b);
+        (1..=3).contains(&diff)
+    })
+}
+
+fn all_decreasing(xs: &[i32]) -> bool {
+    xs.is_sorted_by(|a, b| a > b)
+}
+
+fn all_increasing(xs: &[i32]) -> bool {
+    xs.is_sorted_by(|a, b| a < b)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<usize, SolveError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: &Input) -> Result<usize, SolveError> {
+        Ok(solve2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+    #[test]
+    fn test_solve1() {
+        let input = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve1(&input), 2);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve2(&input), 4);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input("7 6 4\n1 2 x 4\n").unwrap_err();
+        assert_eq!(err.line, Some(2));
+        assert_eq!(err.column, Some(5));
+        assert_eq!(err.text.as_deref(), Some("x"));
+    }
+}
@@ -1,8 +1,11 @@
-use std::fs::{self};
-
 use lazy_static::lazy_static;
 use regex::Regex;
 
+use crate::{
+    error::{ParseError, SolveError},
+    solution::Solution,
+};
+
 #[derive(Debug)]
 enum Cmd {
     Do,
@@ -11,7 +14,7 @@ enum Cmd {
 }
 
 #[derive(Debug)]
-struct Input {
+pub struct Input {
     cmds: Vec<Cmd>,
 }
 
@@ -20,7 +23,7 @@ lazy_static! {
         Regex::new(r"mul\(([0-9]{1,3}),([0-9]{1,3})\)|(do\(\))|(don't\(\))").unwrap();
 }
 
-fn parse_input(s: &str) -> Input {
+fn parse_input(s: &str) -> Result<Input, ParseError> {
     // You could be a bit faster by just skipping sections of text
     // enclosed in a dont..do block, instead of parsing everything.
     // This will do though.
@@ -33,14 +36,26 @@ fn parse_input(s: &str) -> Input {
             if c.get(4).is_some() {
                 return Cmd::Dont;
             }
+            // The regex only matches up to three digits, so these can't fail.
             Cmd::Mul(c[1].parse().unwrap(), c[2].parse().unwrap())
         })
         .collect();
 
-    Input { cmds }
+    Ok(Input { cmds })
+}
+
+fn solve1(input: &Input) -> i32 {
+    input
+        .cmds
+        .iter()
+        .filter_map(|x| match x {
+            Cmd::Mul(a, b) => Some(a * b),
+            _ => None,
+        })
+        .sum()
 }
 
-fn solve(input: &Input) -> i32 {
+fn solve2(input: &Input) -> i32 {
     input
         .cmds
         .iter()
@@ -64,13 +79,24 @@ fn solve(input: &Input) -> i32 {
         .sum()
 }
 
-fn main() {
-    let input_str = fs::read_to_string("data/day03/input").unwrap();
-    let input = parse_input(&input_str);
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Input;
+    type Answer1 = i32;
+    type Answer2 = i32;
 
-    let answer = solve(&input);
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<i32, SolveError> {
+        Ok(solve1(input))
+    }
 
-    println!("{}", answer);
+    fn part2(input: &Input) -> Result<i32, SolveError> {
+        Ok(solve2(input))
+    }
 }
 
 #[cfg(test)]
@@ -80,10 +106,21 @@ mod tests {
     #[test]
     fn test_solve1() {
         let input_str = "\
+xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input);
+
+        assert_eq!(answer, 161);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input_str = "\
 xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
 ";
-        let input = parse_input(&input_str);
-        let answer = solve(&input);
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input);
 
         assert_eq!(answer, 48);
     }
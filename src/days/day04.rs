@@ -0,0 +1,143 @@
+use crate::{
+    direction::EightWayDirection,
+    error::{ParseError, SolveError},
+    grid::Grid,
+    position::Position,
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Input {
+    grid: Grid<char>,
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let grid = s.parse()?;
+    Ok(Input { grid })
+}
+
+fn solve1(input: &Input) -> usize {
+    // Reading right and down-right in each of the four rotations
+    // covers all eight directions.
+    let mut grid = input.grid.clone();
+    let mut count = 0;
+    for _ in 0..4 {
+        count += count_xmases(&grid);
+        grid = grid.rotate_cw();
+    }
+    count
+}
+
+fn solve2(input: &Input) -> usize {
+    // Could speed up slightly by not iterating the edges
+    // where the X is guaranteed to go out of bounds.
+    input
+        .grid
+        .pos_iter()
+        .filter(|pos| is_x_mas(&input.grid, *pos))
+        .count()
+}
+
+const XMAS: [char; 4] = ['X', 'M', 'A', 'S'];
+
+fn count_xmases(grid: &Grid<char>) -> usize {
+    let across = grid
+        .iter_rows()
+        .map(|row| row.windows(4).filter(|w| *w == XMAS).count())
+        .sum::<usize>();
+    let diagonal = grid
+        .pos_iter()
+        .filter(|p| {
+            XMAS.iter()
+                .zip(0..)
+                .all(|(c, i)| grid.try_get_signed(p.x + i, p.y + i) == Some(c))
+        })
+        .count();
+    across + diagonal
+}
+
+fn is_x_mas(grid: &Grid<char>, pos: Position) -> bool {
+    if grid.try_get_pos(&pos) != Some(&'A') {
+        return false;
+    }
+
+    let has_first_diag = matches!(
+        (
+            grid.try_get_pos(&pos.move_in_direction8(EightWayDirection::UpLeft)),
+            grid.try_get_pos(&pos.move_in_direction8(EightWayDirection::DownRight)),
+        ),
+        (Some(&'M'), Some(&'S')) | (Some(&'S'), Some(&'M'))
+    );
+
+    if !has_first_diag {
+        return false;
+    }
+
+    let has_second_diag = matches!(
+        (
+            grid.try_get_pos(&pos.move_in_direction8(EightWayDirection::UpRight)),
+            grid.try_get_pos(&pos.move_in_direction8(EightWayDirection::DownLeft)),
+        ),
+        (Some(&'M'), Some(&'S')) | (Some(&'S'), Some(&'M'))
+    );
+
+    if !has_second_diag {
+        return false;
+    }
+
+    true
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<usize, SolveError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: &Input) -> Result<usize, SolveError> {
+        Ok(solve2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    #[test]
+    fn test_solve1() {
+        let input = parse_input(EXAMPLE).unwrap();
+        let answer = solve1(&input);
+
+        assert_eq!(answer, 18);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input = parse_input(EXAMPLE).unwrap();
+        let answer = solve2(&input);
+
+        assert_eq!(answer, 9);
+    }
+}
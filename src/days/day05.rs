@@ -0,0 +1,176 @@
+use crate::{
+    error::{numbered_lines, parse_field, ParseError, SolveError},
+    graph::Graph,
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Input {
+    rules: Graph<i32>,
+    updates: Vec<Vec<i32>>,
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let lines = numbered_lines(s).collect::<Vec<_>>();
+    let mut sections = lines.splitn(2, |(_, l)| l.is_empty());
+    let rule_lines = sections.next().unwrap_or_default();
+    let update_lines = sections
+        .next()
+        .ok_or_else(|| ParseError::new("missing blank line before the updates"))?;
+
+    let mut rules = Graph::directed();
+    for &(n, l) in rule_lines {
+        let (before, after) = parse_rule(l).map_err(|e| e.at_line(n))?;
+        rules.add_edge(before, after);
+    }
+
+    let updates = update_lines
+        .iter()
+        .map(|&(n, l)| {
+            l.split(',')
+                .map(|x| parse_field(l, x))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| e.at_line(n))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Input { rules, updates })
+}
+
+fn parse_rule(l: &str) -> Result<(i32, i32), ParseError> {
+    let (a, b) = l
+        .split_once('|')
+        .ok_or_else(|| ParseError::invalid("expected a rule like 47|53", l))?;
+    Ok((parse_field(l, a)?, parse_field(l, b)?))
+}
+
+fn solve1(input: &Input) -> i32 {
+    input
+        .updates
+        .iter()
+        .filter(|u| input.rules.check_order(u).is_ok())
+        .map(|u| u[u.len() / 2])
+        .sum()
+}
+
+fn solve2(input: &Input) -> Result<i32, SolveError> {
+    let mut total = 0;
+    for u in &input.updates {
+        if let Some(fixed) = fix_order(&input.rules, u)? {
+            total += fixed[fixed.len() / 2];
+        }
+    }
+    Ok(total)
+}
+
+// Sorts the pages of an update that breaks the rules by the rules between
+// just those pages, which need not order every pair of them directly.
+fn fix_order(rules: &Graph<i32>, u: &[i32]) -> Result<Option<Vec<i32>>, SolveError> {
+    if rules.check_order(u).is_ok() {
+        return Ok(None);
+    }
+    let compare = rules.comparator(u).map_err(|cycle| {
+        SolveError::new(format!("the rules for pages {:?} form a cycle", cycle))
+    })?;
+    let mut fixed = u.to_vec();
+    fixed.sort_by(compare);
+    Ok(Some(fixed))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Input;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<i32, SolveError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: &Input) -> Result<i32, SolveError> {
+        solve2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn test_solve1() {
+        let input = parse_input(EXAMPLE).unwrap();
+        let answer = solve1(&input);
+
+        assert_eq!(answer, 143);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input = parse_input(EXAMPLE).unwrap();
+        let answer = solve2(&input).unwrap();
+
+        assert_eq!(answer, 123);
+    }
+
+    #[test]
+    fn test_fix_order_partial_rules() {
+        // 1 must come before 3 only by way of 2, which is not in the update,
+        // so 1 and 3 may go either way round.
+        let input = parse_input("3|4\n1|2\n2|3\n\n4,3,1\n").unwrap();
+        let fixed = fix_order(&input.rules, &input.updates[0]).unwrap();
+
+        assert_eq!(fixed, Some(vec![3, 4, 1]));
+    }
+
+    #[test]
+    fn test_fix_order_cycle() {
+        let input = parse_input("1|2\n2|3\n3|1\n\n2,1,3\n").unwrap();
+        let err = solve2(&input).unwrap_err();
+
+        assert_eq!(err.message, "the rules for pages [3, 1, 2] form a cycle");
+    }
+
+    #[test]
+    fn test_violated_rule() {
+        let input = parse_input(EXAMPLE).unwrap();
+        let violation = input.rules.check_order(&input.updates[3]).unwrap_err();
+
+        assert_eq!((violation.before, violation.after), (97, 75));
+    }
+}
@@ -0,0 +1,182 @@
+use std::iter;
+
+use crate::{
+    algorithms::is_looping,
+    bit_grid::BitGrid,
+    direction::Direction,
+    error::{ParseError, SolveError},
+    grid::Grid,
+    position::Position,
+    solution::Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TerrainType {
+    Free,
+    Blocked,
+    Guard,
+}
+
+#[derive(Debug)]
+pub struct Input {
+    grid: Grid<TerrainType>,
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let grid = Grid::parse_with(s, |c| match c {
+        '.' => Some(TerrainType::Free),
+        '#' => Some(TerrainType::Blocked),
+        '^' => Some(TerrainType::Guard),
+        _ => None,
+    })?;
+    Ok(Input { grid })
+}
+
+fn find_guard(grid: &Grid<TerrainType>) -> Result<Position, SolveError> {
+    grid.position(|e| *e == TerrainType::Guard)
+        .ok_or_else(|| SolveError::new("no guard in the map"))
+}
+
+fn solve1(input: &Input) -> Result<usize, SolveError> {
+    let mut pos = find_guard(&input.grid)?;
+    let mut seen = BitGrid::new(input.grid.width, input.grid.height());
+
+    let mut facing_dir = Direction::Up;
+    loop {
+        seen.insert(pos);
+        let candidate_pos = pos.move_in_direction(facing_dir);
+        match input.grid.try_get_pos(&candidate_pos) {
+            Some(TerrainType::Blocked) => {
+                facing_dir = facing_dir.rotate_cw();
+            }
+            Some(TerrainType::Free) | Some(TerrainType::Guard) => {
+                pos = candidate_pos;
+            }
+            None => {
+                break;
+            }
+        };
+    }
+
+    Ok(seen.count())
+}
+
+fn solve2(input: &Input) -> Result<usize, SolveError> {
+    let start_pos = find_guard(&input.grid)?;
+    let start_facing_dir = Direction::Up;
+
+    // Each position only needs trying once, however often the path crosses it.
+    let mut tried = BitGrid::new(input.grid.width, input.grid.height());
+    tried.insert(start_pos);
+    let candidates = iter_path(&input.grid, start_pos, start_facing_dir, None)
+        .map(|(pos, _)| pos)
+        .filter(|&obstacle_pos| {
+            tried.insert(obstacle_pos)
+                && is_valid_obstacle_candidate(
+                    &input.grid,
+                    start_pos,
+                    start_facing_dir,
+                    obstacle_pos,
+                )
+        })
+        .count();
+
+    Ok(candidates)
+}
+
+fn iter_path(
+    grid: &Grid<TerrainType>,
+    start_pos: Position,
+    start_facing_dir: Direction,
+    extra_obstacle: Option<Position>,
+) -> impl Iterator<Item = (Position, Direction)> + Clone + '_ {
+    iter::successors(Some((start_pos, start_facing_dir)), move |prev| {
+        let candidate_pos = prev.0.move_in_direction(prev.1);
+        (extra_obstacle == Some(candidate_pos))
+            .then_some(&TerrainType::Blocked)
+            .or_else(|| grid.try_get_pos(&candidate_pos))
+            .map(|terrain| match terrain {
+                TerrainType::Blocked => (prev.0, prev.1.rotate_cw()),
+                TerrainType::Free | TerrainType::Guard => (candidate_pos, prev.1),
+            })
+    })
+}
+
+fn is_valid_obstacle_candidate(
+    grid: &Grid<TerrainType>,
+    pos: Position,
+    facing_dir: Direction,
+    obstacle_pos: Position,
+) -> bool {
+    match grid.try_get_pos(&obstacle_pos) {
+        Some(TerrainType::Free) | Some(TerrainType::Guard) => {
+            is_looping(&iter_path(grid, pos, facing_dir, Some(obstacle_pos)))
+        }
+        Some(TerrainType::Blocked) | None => false,
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<usize, SolveError> {
+        solve1(input)
+    }
+
+    fn part2(input: &Input) -> Result<usize, SolveError> {
+        solve2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve1() {
+        let input_str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input).unwrap();
+
+        assert_eq!(answer, 41);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input_str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input).unwrap();
+
+        assert_eq!(answer, 6);
+    }
+}
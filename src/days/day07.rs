@@ -0,0 +1,168 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{
+    error::{parse_field, parse_lines, parse_str, ParseError, SolveError},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Input {
+    lines: Vec<(i64, Vec<i64>)>,
+}
+
+lazy_static! {
+    static ref LINE_REGEX: Regex = Regex::new(r"^([0-9]+): ([0-9 ]+)$").unwrap();
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let lines = parse_lines(s, |l| {
+        let captures = LINE_REGEX
+            .captures(l)
+            .ok_or_else(|| ParseError::invalid("expected a line like 190: 10 19", l))?;
+        let nums = captures[2]
+            .split_ascii_whitespace()
+            .map(|n| match parse_field(l, n)? {
+                // Solving divides by each number in turn.
+                0 => Err(ParseError::invalid("numbers must be positive", n).within(l, n)),
+                v => Ok(v),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok((parse_field(l, &captures[1])?, nums))
+    })?;
+    Ok(Input { lines })
+}
+
+fn solve1(input: &Input) -> i64 {
+    input
+        .lines
+        .iter()
+        .filter(|(test_val, nums)| can_make(*test_val, nums, false))
+        .map(|(test_val, _)| test_val)
+        .sum()
+}
+
+fn solve2(input: &Input) -> i64 {
+    input
+        .lines
+        .iter()
+        .filter(|(test_val, nums)| can_make(*test_val, nums, true))
+        .map(|(test_val, _)| test_val)
+        .sum()
+}
+
+fn can_make(target_val: i64, nums: &[i64], allow_concat: bool) -> bool {
+    // We'll go backwards from the target number to the inputs, right to left.
+    let Some((&last_num, rest)) = nums.split_last() else {
+        // If we have no more nums left, we either reduced the target val exactly
+        // and got to 0, in which case we succeeded, or there is still a number
+        // left to reduce, in which case we failed.
+        return target_val == 0;
+    };
+
+    if target_val % last_num == 0 {
+        // Remainder is zero so we could have multiplied
+        if can_make(target_val / last_num, rest, allow_concat) {
+            return true;
+        }
+    }
+
+    if target_val >= last_num {
+        // target is equal or larger so we could have added
+        if can_make(target_val - last_num, rest, allow_concat) {
+            return true;
+        }
+    }
+
+    if !allow_concat {
+        return false;
+    }
+
+    if let Some(suffixless_num) = target_val
+        .to_string()
+        .strip_suffix(&last_num.to_string())
+        // Nothing is left if the target is exactly our digits,
+        // and nothing can't have been concatenated onto.
+        .and_then(|v| parse_str(v).ok())
+    {
+        // target ends with our digits, so we could have concated
+        if can_make(suffixless_num, rest, allow_concat) {
+            return true;
+        }
+    }
+
+    false
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Input;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<i64, SolveError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: &Input) -> Result<i64, SolveError> {
+        Ok(solve2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve1() {
+        let input_str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input);
+
+        assert_eq!(answer, 3749);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input_str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input);
+
+        assert_eq!(answer, 11387);
+    }
+
+    #[test]
+    fn test_zero_is_rejected() {
+        let err = parse_input("10: 5 0\n").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 7: numbers must be positive: \"0\""
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use std::{
+    collections::{HashMap, HashSet},
+    iter,
+};
+
+use crate::{
+    error::{ParseError, SolveError},
+    grid::Grid,
+    position::Position,
+    solution::Solution,
+    sparse_grid::SparseGrid,
+};
+
+#[derive(Debug)]
+pub struct Input {
+    // Only the antennas are stored; the bounds still cover the whole map.
+    grid: SparseGrid<char>,
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let grid = s.parse::<Grid<char>>()?;
+    Ok(Input {
+        grid: SparseGrid::from_grid(grid, '.'),
+    })
+}
+
+fn solve1(input: &Input) -> usize {
+    let station_groups = group_stations(&input.grid);
+
+    let antinode_locations: HashSet<Position> = station_groups
+        .values()
+        .flat_map(|stations| gen_antinodes(stations))
+        .filter(|p| input.grid.is_in_bounds(p))
+        .collect();
+
+    antinode_locations.len()
+}
+
+fn group_stations(grid: &SparseGrid<char>) -> HashMap<char, Vec<Position>> {
+    let mut groups = HashMap::<char, Vec<Position>>::new();
+    for (pos, c) in grid.iter() {
+        groups
+            .entry(*c)
+            .and_modify(|v| {
+                v.push(pos);
+            })
+            .or_insert_with(|| vec![pos]);
+    }
+    groups
+}
+
+fn gen_antinodes(stations: &[Position]) -> impl Iterator<Item = Position> + '_ {
+    let pairs = stations
+        .iter()
+        .enumerate()
+        .flat_map(|(i, p)| stations[i + 1..].iter().map(move |q| (p, q)));
+
+    pairs.flat_map(|(p, q)| {
+        let dist_x = q.x - p.x;
+        let dist_y = q.y - p.y;
+        let antinode_1 = Position::new(q.x + dist_x, q.y + dist_y);
+        let antinode_2 = Position::new(p.x - dist_x, p.y - dist_y);
+        [antinode_1, antinode_2]
+    })
+}
+
+fn solve2(input: &Input) -> usize {
+    let station_groups = group_stations(&input.grid);
+
+    let antinode_locations: HashSet<Position> = station_groups
+        .values()
+        .flat_map(|stations| gen_resonant_antinodes(&input.grid, stations))
+        .collect();
+
+    antinode_locations.len()
+}
+
+fn gen_resonant_antinodes<'a>(
+    grid: &'a SparseGrid<char>,
+    stations: &'a [Position],
+) -> impl Iterator<Item = Position> + 'a {
+    let pairs = stations
+        .iter()
+        .enumerate()
+        .flat_map(|(i, p)| stations[i + 1..].iter().map(move |q| (p, q)));
+
+    pairs.flat_map(move |(p, q)| {
+        let dist_x = q.x - p.x;
+        let dist_y = q.y - p.y;
+        gen_in_bounds(grid, *p, (dist_x, dist_y))
+    })
+}
+
+// Every position on the line through p in steps of dist that lies within the grid.
+fn gen_in_bounds(
+    grid: &SparseGrid<char>,
+    p: Position,
+    dist: (i64, i64),
+) -> impl Iterator<Item = Position> + '_ {
+    iter::successors(Some(p), move |p| {
+        Some(Position::new(p.x + dist.0, p.y + dist.1))
+    })
+    .take_while(|p| grid.is_in_bounds(p))
+    .chain(
+        iter::successors(Some(p), move |p| {
+            Some(Position::new(p.x - dist.0, p.y - dist.1))
+        })
+        .skip(1)
+        .take_while(|p| grid.is_in_bounds(p)),
+    )
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<usize, SolveError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: &Input) -> Result<usize, SolveError> {
+        Ok(solve2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve1() {
+        let input_str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input);
+
+        assert_eq!(answer, 14);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input_str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input);
+
+        assert_eq!(answer, 34);
+    }
+}
@@ -0,0 +1,201 @@
+use std::iter;
+
+use crate::{
+    error::{parse_chars, ParseError, SolveError},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Input {
+    line: Vec<u8>,
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let l = s
+        .lines()
+        .next()
+        .ok_or_else(|| ParseError::new("empty input"))?;
+    let line = parse_chars(l, |c| c.to_digit(10).map(|d| d as u8)).map_err(|e| e.at_line(1))?;
+    Ok(Input { line })
+}
+
+fn solve1(input: &Input) -> usize {
+    // You can definitely save memory by not expanding out the runs and writing
+    // a fancier algorithm. I did noodle with doing that but I found it too
+    // fiddly for a first pass so I went back to the dumb brute force algo
+    // instead. The full expanded array is only like ~200k elems.
+    let mut blocks = expand(&input.line);
+    compact_blocks(&mut blocks);
+    compute_block_checksum(&blocks)
+}
+
+fn expand(line: &[u8]) -> Vec<Option<usize>> {
+    line.chunks(2)
+        .enumerate()
+        .flat_map(|(i, c)| {
+            iter::repeat_n(Some(i), c[0].into())
+                .chain(iter::repeat_n(None, c.get(1).copied().unwrap_or(0).into()))
+        })
+        .collect()
+}
+
+fn compute_block_checksum(blocks: &[Option<usize>]) -> usize {
+    blocks
+        .iter()
+        .enumerate()
+        .filter_map(|(pos, id)| id.map(|id| pos * id))
+        .sum()
+}
+
+fn compact_blocks(blocks: &mut [Option<usize>]) {
+    let mut idx = 0;
+    let mut end_idx = blocks.len() - 1;
+
+    while idx < end_idx {
+        let Some(val) = blocks[end_idx] else {
+            end_idx -= 1;
+            continue;
+        };
+
+        if blocks[idx].is_some() {
+            idx += 1;
+            continue;
+        }
+
+        blocks[idx] = Some(val);
+        blocks[end_idx] = None;
+        idx += 1;
+        end_idx -= 1;
+    }
+}
+
+fn solve2(input: &Input) -> usize {
+    let runs = to_runs(&input.line);
+    let mut files = runs
+        .iter()
+        .scan(0usize, |pos, r| {
+            let start = *pos;
+            *pos += usize::from(r.len);
+            Some((start, r))
+        })
+        .filter_map(|(p, r)| r.file_id.map(|id| (p, id, r.len)))
+        .collect::<Vec<_>>();
+
+    let mut frees = runs
+        .iter()
+        .scan(0usize, |pos, r| {
+            let start = *pos;
+            *pos += usize::from(r.len);
+            Some((start, r))
+        })
+        .filter_map(|(p, r)| r.file_id.is_none().then_some((p, r.len)))
+        .collect::<Vec<_>>();
+
+    compact_files(&mut files, &mut frees);
+    compute_files_checksum(&files)
+}
+
+fn compute_files_checksum(files: &[(usize, usize, u8)]) -> usize {
+    files
+        .iter()
+        .map(|(pos, id, len)| compute_file_checksum(*pos, *id, *len))
+        .sum()
+}
+
+fn compute_file_checksum(pos: usize, file_id: usize, len: u8) -> usize {
+    let tri = triangle(usize::from(len) - 1);
+    (tri * file_id) + (pos * usize::from(len) * file_id)
+}
+
+fn compact_files(files: &mut [(usize, usize, u8)], frees: &mut [(usize, u8)]) {
+    files.iter_mut().rev().for_each(|(file_pos, _, file_len)| {
+        let free = frees
+            .iter_mut()
+            .take_while(|(pos, _)| *pos < *file_pos)
+            .find(|(_, len)| *len >= *file_len);
+        if let Some((free_pos, free_len)) = free {
+            *file_pos = *free_pos;
+            *free_pos += usize::from(*file_len);
+            *free_len -= *file_len;
+        }
+    });
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Run {
+    len: u8,
+    file_id: Option<usize>,
+}
+
+impl Run {
+    fn file(len: u8, file_id: usize) -> Self {
+        Run {
+            len,
+            file_id: Some(file_id),
+        }
+    }
+    fn free(len: u8) -> Self {
+        Run { len, file_id: None }
+    }
+}
+
+fn to_runs(line: &[u8]) -> Vec<Run> {
+    line.chunks(2)
+        .enumerate()
+        .flat_map(|(i, c)| {
+            [Some(Run::file(c[0], i)), c.get(1).copied().map(Run::free)]
+                .into_iter()
+                .flatten()
+        })
+        .collect()
+}
+
+fn triangle(n: usize) -> usize {
+    (n * (n + 1)) / 2
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<usize, SolveError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: &Input) -> Result<usize, SolveError> {
+        Ok(solve2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve1() {
+        let input_str = "\
+2333133121414131402
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input);
+
+        assert_eq!(answer, 1928);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input_str = "\
+2333133121414131402
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input);
+        assert_eq!(answer, 2858);
+    }
+}
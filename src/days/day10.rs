@@ -0,0 +1,171 @@
+use crate::{
+    algorithms::grid_bfs,
+    error::{ParseError, SolveError},
+    grid::Grid,
+    position::Position,
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Input {
+    heightmap: Grid<u8>,
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    // Impassable cells are marked with '.' in some of the examples,
+    // so we give them a height that can never be reached.
+    let grid = Grid::parse_with(s, |c| match c {
+        '.' => Some(100),
+        _ => c.to_digit(10).map(|d| d as u8),
+    })?;
+    Ok(Input { heightmap: grid })
+}
+
+fn solve1(input: &Input) -> usize {
+    input
+        .heightmap
+        .enumerate()
+        .filter(|(_, val)| **val == 0)
+        .map(|(p, _)| get_score(&input.heightmap, p))
+        .sum()
+}
+
+fn get_score(grid: &Grid<u8>, start_pos: Position) -> usize {
+    let distances = grid_bfs(grid, &[start_pos], |from, to| *to == from + 1);
+    grid.iter()
+        .zip(distances.iter())
+        .filter(|(val, d)| **val == 9 && d.is_some())
+        .count()
+}
+
+fn solve2(input: &Input) -> usize {
+    input
+        .heightmap
+        .enumerate()
+        .filter(|(_, val)| **val == 0)
+        .map(|(p, _)| get_rating(&input.heightmap, p))
+        .sum()
+}
+
+fn get_rating(grid: &Grid<u8>, start_pos: Position) -> usize {
+    let val = *grid.get_pos(&start_pos);
+    if val == 9 {
+        return 1;
+    }
+
+    grid.neighbours(start_pos)
+        .filter(|(_, v)| **v == val + 1)
+        .map(|(p, _)| get_rating(grid, p))
+        .sum()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<usize, SolveError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: &Input) -> Result<usize, SolveError> {
+        Ok(solve2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve1() {
+        let input_str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input);
+
+        assert_eq!(answer, 36);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input_str = "\
+.....0.
+..4321.
+..5..2.
+..6543.
+..7..4.
+..8765.
+..9....
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input);
+
+        assert_eq!(answer, 3);
+    }
+
+    #[test]
+    fn test_solve2_2() {
+        let input_str = "\
+..90..9
+...1.98
+...2..7
+6543456
+765.987
+876....
+987....
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input);
+
+        assert_eq!(answer, 13);
+    }
+
+    #[test]
+    fn test_solve2_3() {
+        let input_str = "\
+012345
+123456
+234567
+345678
+4.6789
+56789.
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input);
+
+        assert_eq!(answer, 227);
+    }
+
+    #[test]
+    fn test_solve2_4() {
+        let input_str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input);
+
+        assert_eq!(answer, 81);
+    }
+}
@@ -0,0 +1,130 @@
+use std::iter;
+
+use crate::{
+    error::{parse_field, ParseError, SolveError},
+    memo::Memo,
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Input {
+    stones: Vec<u64>,
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let l = s
+        .lines()
+        .next()
+        .ok_or_else(|| ParseError::new("empty input"))?;
+    let stones = l
+        .split_ascii_whitespace()
+        .map(|n| parse_field(l, n))
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.at_line(1))?;
+    Ok(Input { stones })
+}
+
+fn solve1(input: &Input) -> usize {
+    let num_blinks = 25;
+
+    iter::successors(Some(input.stones.clone()), |stones| {
+        Some(blink(stones.iter().copied()).collect())
+    })
+    .take(num_blinks + 1) // also count initial state
+    .last()
+    .unwrap()
+    .len()
+}
+
+fn blink(stones: impl Iterator<Item = u64>) -> impl Iterator<Item = u64> {
+    stones.flat_map(|stone| {
+        let (left, right) = blink_stone(stone);
+        [Some(left), right].into_iter().flatten()
+    })
+}
+
+fn blink_stone(s: u64) -> (u64, Option<u64>) {
+    if s == 0 {
+        (1, None)
+    } else if let Some((left, right)) = split_digits(s) {
+        (left, Some(right))
+    } else {
+        (s * 2024, None)
+    }
+}
+
+fn split_digits(s: u64) -> Option<(u64, u64)> {
+    let str = s.to_string();
+    if !str.len().is_multiple_of(2) {
+        return None;
+    }
+
+    let half_len = str.len() / 2;
+    let left = str[..half_len].parse().unwrap();
+    let right = str[half_len..].parse().unwrap();
+    Some((left, right))
+}
+
+fn solve2(input: &Input, num_blinks: u64) -> u64 {
+    // The number of stones a single stone becomes after some blinks.
+    let mut stones_after = Memo::new(|memo: &mut Memo<(u64, u64), u64>, (s, blinks)| {
+        if blinks == 0 {
+            return 1;
+        }
+        let (s1, s2) = blink_stone(s);
+        memo.get((s1, blinks - 1)) + s2.map(|s2| memo.get((s2, blinks - 1))).unwrap_or(0)
+    });
+
+    input
+        .stones
+        .iter()
+        .map(|s| stones_after.get((*s, num_blinks)))
+        .sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<usize, SolveError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: &Input) -> Result<u64, SolveError> {
+        Ok(solve2(input, 75))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve1() {
+        let input_str = "\
+125 17
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input);
+
+        assert_eq!(answer, 55312);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input_str = "\
+125 17
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input, 25);
+
+        assert_eq!(answer, 55312);
+    }
+}
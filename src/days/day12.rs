@@ -0,0 +1,172 @@
+use crate::{
+    algorithms::IndexDisjointSet,
+    direction::Direction,
+    error::{ParseError, SolveError},
+    grid::Grid,
+    position::Position,
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Input {
+    grid: Grid<char>,
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let grid = s.parse()?;
+    Ok(Input { grid })
+}
+
+fn solve1(input: &Input) -> usize {
+    total_price(&input.grid, |_, num_neighbours| 4 - num_neighbours)
+}
+
+fn solve2(input: &Input) -> usize {
+    total_price(&input.grid, |p, _| count_corners(&input.grid, p))
+}
+
+// The price of each region is its area multiplied by
+// the sum of edge_cost over all the cells in the region.
+fn total_price<F>(grid: &Grid<char>, edge_cost: F) -> usize
+where
+    F: Fn(Position, usize) -> usize,
+{
+    let mut regions = IndexDisjointSet::new(grid.vec.len());
+    let mut edges = vec![0; grid.vec.len()];
+    for (i, (pos, val)) in grid.enumerate().enumerate() {
+        let mut num_neighbours = 0;
+        for (n, _) in grid.neighbours(pos).filter(|(_, v)| *v == val) {
+            regions.union(i, grid.pos_to_vec_index(&n).unwrap());
+            num_neighbours += 1;
+        }
+        edges[i] = edge_cost(pos, num_neighbours);
+    }
+
+    regions
+        .components()
+        .into_iter()
+        .map(|region| region.len() * region.iter().map(|&i| edges[i]).sum::<usize>())
+        .sum()
+}
+
+// counting corners is a lot easier than counting sides,
+// and the number of corners always equals the number of sides.
+fn count_corners(grid: &Grid<char>, p: Position) -> usize {
+    // each of the 4 corners of our cell can either be part of a convex corner
+    // of the shape or a concave corner of the shape.
+
+    let val = *grid.get_pos(&p);
+    let is_diff_region = |p: Position| grid.try_get_pos(&p).map(|v| *v != val).unwrap_or(true);
+    let is_convex_corner = |vert: Direction, horiz: Direction| {
+        is_diff_region(p.move_in_direction(vert)) && is_diff_region(p.move_in_direction(horiz))
+    };
+    let is_concave_corner = |vert: Direction, horiz: Direction| {
+        !is_diff_region(p.move_in_direction(vert))
+            && !is_diff_region(p.move_in_direction(horiz))
+            && is_diff_region(p.move_in_direction(vert).move_in_direction(horiz))
+    };
+
+    [Direction::Up, Direction::Down]
+        .into_iter()
+        .flat_map(|v| {
+            [Direction::Left, Direction::Right]
+                .into_iter()
+                .map(move |h| (v, h))
+        })
+        .flat_map(|(v, h)| {
+            [
+                usize::from(is_convex_corner(v, h)),
+                usize::from(is_concave_corner(v, h)),
+            ]
+        })
+        .sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<usize, SolveError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: &Input) -> Result<usize, SolveError> {
+        Ok(solve2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve1() {
+        let input_str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input);
+
+        assert_eq!(answer, 1930);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input_str = "\
+AAAA
+BBCD
+BBCC
+EEEC
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input);
+
+        assert_eq!(answer, 80);
+    }
+
+    #[test]
+    fn test_solve2_2() {
+        let input_str = "\
+EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input);
+
+        assert_eq!(answer, 236);
+    }
+    #[test]
+    fn test_solve2_3() {
+        let input_str = "\
+AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input);
+
+        assert_eq!(answer, 368);
+    }
+}
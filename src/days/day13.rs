@@ -0,0 +1,185 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{
+    error::{parse_field, sections, ParseError, SolveError},
+    math::solve_linear_2x2,
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Input {
+    games: Vec<Game>,
+}
+
+#[derive(Debug)]
+struct Game {
+    a: (i64, i64),
+    b: (i64, i64),
+    prize: (i64, i64),
+}
+
+lazy_static! {
+    static ref LINE_A_REGEX: Regex = Regex::new(r"^Button A: X\+([0-9]+), Y\+([0-9]+)$").unwrap();
+    static ref LINE_B_REGEX: Regex = Regex::new(r"^Button B: X\+([0-9]+), Y\+([0-9]+)$").unwrap();
+    static ref PRIZE_REGEX: Regex = Regex::new(r"^Prize: X=([0-9]+), Y=([0-9]+)$").unwrap();
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let games = sections(s)
+        .iter()
+        .map(|lines| parse_game(lines))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Input { games })
+}
+
+fn parse_game(lines: &[(usize, &str)]) -> Result<Game, ParseError> {
+    let [a, b, prize] = lines else {
+        return Err(ParseError::new("expected three lines per machine").at_line(lines[0].0));
+    };
+    Ok(Game {
+        a: parse_line(*a, &LINE_A_REGEX, "Button A: X+94, Y+34")?,
+        b: parse_line(*b, &LINE_B_REGEX, "Button B: X+22, Y+67")?,
+        prize: parse_line(*prize, &PRIZE_REGEX, "Prize: X=8400, Y=5400")?,
+    })
+}
+
+fn parse_line(
+    (n, l): (usize, &str),
+    regex: &Regex,
+    example: &str,
+) -> Result<(i64, i64), ParseError> {
+    let captures = regex.captures(l).ok_or_else(|| {
+        ParseError::invalid(format!("expected a line like {}", example), l).at_line(n)
+    })?;
+    let x = parse_field(l, &captures[1]).map_err(|e| e.at_line(n))?;
+    let y = parse_field(l, &captures[2]).map_err(|e| e.at_line(n))?;
+    Ok((x, y))
+}
+
+fn solve1(input: &Input) -> i64 {
+    input.games.iter().filter_map(min_tokens).sum()
+}
+
+// Pressing A i times and B j times must land on the prize:
+//
+//   i * a.x + j * b.x = prize.x
+//   i * a.y + j * b.y = prize.y
+//
+// If the buttons move in the same direction there may be many ways to win,
+// so we want the one that costs the fewest tokens.
+fn min_tokens(game: &Game) -> Option<i64> {
+    let (a_pushes, b_pushes) = solve_linear_2x2(
+        [[game.a.0, game.b.0], [game.a.1, game.b.1]],
+        [game.prize.0, game.prize.1],
+    )
+    .cheapest_non_negative(3, 1)?;
+    Some((3 * a_pushes) + b_pushes)
+}
+
+fn solve2(input: &Input) -> i64 {
+    let amount_to_add = 10000000000000;
+    input
+        .games
+        .iter()
+        .map(|game| Game {
+            a: game.a,
+            b: game.b,
+            prize: (game.prize.0 + amount_to_add, game.prize.1 + amount_to_add),
+        })
+        .filter_map(|game| min_tokens(&game))
+        .sum()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Input;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<i64, SolveError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: &Input) -> Result<i64, SolveError> {
+        Ok(solve2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve1() {
+        let input_str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input);
+
+        assert_eq!(answer, 480);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input_str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input);
+
+        assert_eq!(answer, 875318608908);
+    }
+
+    #[test]
+    fn test_collinear_buttons() {
+        let input_str = "\
+Button A: X+1, Y+1
+Button B: X+3, Y+3
+Prize: X=10, Y=10
+
+Button A: X+2, Y+4
+Button B: X+3, Y+6
+Prize: X=1, Y=2
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input);
+
+        // One A and three B for the first prize; the second can't be reached.
+        assert_eq!(answer, 6);
+    }
+}
@@ -0,0 +1,176 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{
+    error::{parse_field, parse_lines, ParseError, SolveError},
+    math::crt,
+    position::Position,
+    solution::Solution,
+    torus::Torus,
+};
+
+#[derive(Debug)]
+pub struct Input {
+    robots: Vec<Robot>,
+}
+
+#[derive(Debug)]
+struct Robot {
+    pos: Position,
+    vel: Position,
+}
+
+lazy_static! {
+    static ref LINE_REGEX: Regex =
+        Regex::new(r"^p=([0-9]+),([0-9]+) v=(-?[0-9]+),(-?[0-9]+)$").unwrap();
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let robots = parse_lines(s, |l| {
+        let captures = LINE_REGEX
+            .captures(l)
+            .ok_or_else(|| ParseError::invalid("expected a line like p=0,4 v=3,-3", l))?;
+        Ok(Robot {
+            pos: Position::new(parse_field(l, &captures[1])?, parse_field(l, &captures[2])?),
+            vel: Position::new(parse_field(l, &captures[3])?, parse_field(l, &captures[4])?),
+        })
+    })?;
+    Ok(Input { robots })
+}
+
+fn solve1(input: &Input, width: i64, height: i64) -> usize {
+    let board = Torus::new(width, height);
+    board
+        .quadrant_counts(
+            input
+                .robots
+                .iter()
+                .map(|robot| board.advance(robot.pos, robot.vel, 100)),
+        )
+        .into_iter()
+        .product()
+}
+
+fn solve2(input: &Input, width: i64, height: i64) -> Result<usize, SolveError> {
+    // The robots' x positions repeat every `width` seconds and their y positions
+    // every `height` seconds. When they draw the tree they are bunched up in both
+    // axes at once, so find the time in each cycle when each axis is least spread
+    // out, then combine the two with the Chinese remainder theorem.
+    let x_time = least_spread_time(width, |t| {
+        input
+            .robots
+            .iter()
+            .map(move |r| (r.pos.x + r.vel.x * t).rem_euclid(width))
+    });
+    let y_time = least_spread_time(height, |t| {
+        input
+            .robots
+            .iter()
+            .map(move |r| (r.pos.y + r.vel.y * t).rem_euclid(height))
+    });
+    let (time, _) = crt(&[(x_time, width), (y_time, height)])
+        .ok_or_else(|| SolveError::new("did not find image"))?;
+
+    Ok(time as usize)
+}
+
+/// The time within one period at which the values are least spread out,
+/// measured by their variance.
+fn least_spread_time<I, F>(period: i64, values_at: F) -> i64
+where
+    I: Iterator<Item = i64>,
+    F: Fn(i64) -> I,
+{
+    (0..period)
+        .min_by_key(|&t| {
+            // n^2 times the variance, which ranks the same and stays an integer.
+            let (n, sum, sum_sq) = values_at(t).fold((0, 0, 0), |(n, sum, sum_sq), v| {
+                (n + 1, sum + v, sum_sq + v * v)
+            });
+            n * sum_sq - sum * sum
+        })
+        .unwrap_or(0)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<usize, SolveError> {
+        Ok(solve1(input, 101, 103))
+    }
+
+    fn part2(input: &Input) -> Result<usize, SolveError> {
+        solve2(input, 101, 103)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::grid::Grid;
+
+    #[test]
+    fn test_solve1() {
+        let input_str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input, 11, 7);
+
+        assert_eq!(answer, 12);
+    }
+
+    #[test]
+    fn test_solve2() {
+        // Every robot passes through (5, 3) at t = 30, and at no other time
+        // within the 77 seconds before the pattern repeats are they all lined up.
+        let velocities: [(i64, i64); 7] =
+            [(1, 2), (2, -1), (-3, 1), (4, 3), (-1, -2), (3, 5), (-2, 4)];
+        let input = Input {
+            robots: velocities
+                .into_iter()
+                .map(|(vx, vy)| Robot {
+                    pos: Position::new((5 - vx * 30).rem_euclid(11), (3 - vy * 30).rem_euclid(7)),
+                    vel: Position::new(vx, vy),
+                })
+                .collect(),
+        };
+        let answer = solve2(&input, 11, 7).unwrap();
+
+        assert_eq!(answer, 30);
+
+        let board = Torus::new(11, 7);
+        let picture = Grid::new(11, 7, '.')
+            .render(|&c| c)
+            .overlay(
+                input
+                    .robots
+                    .iter()
+                    .map(|r| board.advance(r.pos, r.vel, answer as i64)),
+                '#',
+            )
+            .to_string();
+        assert_eq!(picture.matches('#').count(), 1);
+        assert_eq!(picture.lines().nth(3), Some(".....#....."));
+    }
+}
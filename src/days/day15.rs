@@ -0,0 +1,398 @@
+use crate::{
+    direction::Direction,
+    error::{parse_chars, sections, ParseError, SolveError},
+    grid::Grid,
+    position::Position,
+    solution::Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Wall,
+    Box,
+    Floor,
+    Robot,
+}
+
+#[derive(Debug)]
+pub struct Input {
+    grid: Grid<Cell>,
+    instructions: Vec<Direction>,
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let sections = sections(s);
+    let [grid_lines, instruction_lines] = sections.as_slice() else {
+        return Err(ParseError::new(
+            "expected a map and a list of moves separated by a blank line",
+        ));
+    };
+
+    let grid = Grid::parse_section(grid_lines, parse_char)?;
+
+    let instructions = instruction_lines
+        .iter()
+        .map(|&(n, l)| parse_chars(l, parse_instruction).map_err(|e| e.at_line(n)))
+        .collect::<Result<Vec<_>, _>>()?
+        .concat();
+
+    Ok(Input { grid, instructions })
+}
+
+fn parse_char(c: char) -> Option<Cell> {
+    match c {
+        '#' => Some(Cell::Wall),
+        '.' => Some(Cell::Floor),
+        '@' => Some(Cell::Robot),
+        'O' => Some(Cell::Box),
+        _ => None,
+    }
+}
+
+fn parse_instruction(c: char) -> Option<Direction> {
+    match c {
+        '<' => Some(Direction::Left),
+        '^' => Some(Direction::Up),
+        '>' => Some(Direction::Right),
+        'v' => Some(Direction::Down),
+        _ => None,
+    }
+}
+
+fn solve1(input: &Input) -> Result<i64, SolveError> {
+    let mut working_grid = input.grid.clone();
+    let mut robot_pos = input
+        .grid
+        .position(|x| x == &Cell::Robot)
+        .ok_or_else(|| SolveError::new("no robot in the map"))?;
+    for i in &input.instructions {
+        apply_instruction(&mut working_grid, &mut robot_pos, *i);
+    }
+
+    Ok(calc_gps_sum(&working_grid))
+}
+
+fn apply_instruction(g: &mut Grid<Cell>, pos: &mut Position, d: Direction) {
+    let next_pos = pos.move_in_direction(d);
+    // Anything beyond the edge of the map is as good as a wall.
+    let cell_ahead = g.try_get_pos(&next_pos).unwrap_or(&Cell::Wall);
+    match cell_ahead {
+        Cell::Wall => {
+            // do nothing
+        }
+        Cell::Box => {
+            if push_box(g, next_pos, d) {
+                *pos = next_pos;
+            }
+        }
+        Cell::Floor | Cell::Robot => {
+            *pos = next_pos;
+        }
+    }
+}
+
+fn push_box(g: &mut Grid<Cell>, pos: Position, d: Direction) -> bool {
+    assert_eq!(g.get_pos(&pos), &Cell::Box);
+    let next_pos = pos.move_in_direction(d);
+
+    g.try_get_pos(&next_pos)
+        .copied()
+        .map(|cell| match cell {
+            Cell::Wall => false,
+            Cell::Box => {
+                if push_box(g, next_pos, d) {
+                    g.set_pos(&next_pos, Cell::Box);
+                    g.set_pos(&pos, Cell::Floor);
+                    true
+                } else {
+                    false
+                }
+            }
+            Cell::Floor | Cell::Robot => {
+                g.set_pos(&next_pos, Cell::Box);
+                g.set_pos(&pos, Cell::Floor);
+                true
+            }
+        })
+        .unwrap_or(false)
+}
+
+fn calc_gps_sum(g: &Grid<Cell>) -> i64 {
+    g.enumerate()
+        .filter(|(_, c)| c == &&Cell::Box)
+        .map(|(p, _)| (100 * p.y) + p.x)
+        .sum()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BoxSide {
+    Left,
+    Right,
+}
+impl BoxSide {
+    fn opposite_dir(&self) -> Direction {
+        match self {
+            BoxSide::Left => Direction::Right,
+            BoxSide::Right => Direction::Left,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WideCell {
+    Wall,
+    Box(BoxSide),
+    Floor,
+    Robot,
+}
+
+fn solve2(input: &Input) -> Result<i64, SolveError> {
+    let mut working_grid = widen(&input.grid);
+    let mut robot_pos = working_grid
+        .position(|x| x == &WideCell::Robot)
+        .ok_or_else(|| SolveError::new("no robot in the map"))?;
+    for i in &input.instructions {
+        apply_wide_instruction(&mut working_grid, &mut robot_pos, *i)?;
+    }
+
+    Ok(calc_wide_gps_sum(&working_grid))
+}
+
+fn apply_wide_instruction(
+    g: &mut Grid<WideCell>,
+    pos: &mut Position,
+    d: Direction,
+) -> Result<(), SolveError> {
+    let next_pos = pos.move_in_direction(d);
+    if can_push_into(g, next_pos, d) {
+        push_into(g, next_pos, d)?;
+        *pos = next_pos;
+    }
+    Ok(())
+}
+
+// Anything beyond the edge of the map is as good as a wall.
+fn wide_cell(g: &Grid<WideCell>, pos: Position) -> WideCell {
+    g.try_get_pos(&pos).copied().unwrap_or(WideCell::Wall)
+}
+
+fn push_into(g: &mut Grid<WideCell>, pos: Position, d: Direction) -> Result<(), SolveError> {
+    match wide_cell(g, pos) {
+        WideCell::Wall => {
+            return Err(SolveError::new(format!(
+                "hit a wall at {:?} while pushing",
+                pos
+            )))
+        }
+        WideCell::Floor | WideCell::Robot => {
+            // do nothing
+        }
+        WideCell::Box(side) => {
+            match d {
+                Direction::Right | Direction::Left => {
+                    // Need to push both units of the two-wide box.
+                    let next_pos = pos.move_in_direction(d);
+                    let next_next_pos = next_pos.move_in_direction(d);
+
+                    push_into(g, next_next_pos, d)?;
+
+                    g.set_pos(&next_next_pos, wide_cell(g, next_pos));
+                    g.set_pos(&next_pos, wide_cell(g, pos));
+                    g.set_pos(&pos, WideCell::Floor);
+                }
+                Direction::Up | Direction::Down => {
+                    // Need to push both sides of the box.
+                    let other_side_pos = pos.move_in_direction(side.opposite_dir());
+                    let next_pos = pos.move_in_direction(d);
+                    let next_other_side_pos = other_side_pos.move_in_direction(d);
+
+                    push_into(g, next_pos, d)?;
+
+                    g.set_pos(&next_pos, wide_cell(g, pos));
+                    g.set_pos(&pos, WideCell::Floor);
+
+                    push_into(g, next_other_side_pos, d)?;
+
+                    g.set_pos(&next_other_side_pos, wide_cell(g, other_side_pos));
+                    g.set_pos(&other_side_pos, WideCell::Floor);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn can_push_into(g: &Grid<WideCell>, pos: Position, d: Direction) -> bool {
+    match wide_cell(g, pos) {
+        WideCell::Wall => false,
+        WideCell::Floor | WideCell::Robot => true,
+        WideCell::Box(side) => match d {
+            Direction::Up | Direction::Down => {
+                // Boxes are two wide, so pushing up or down can mean that a box
+                // may push on two other boxes. Check both sides.
+                let other_side_pos = pos.move_in_direction(side.opposite_dir());
+                can_push_into(g, pos.move_in_direction(d), d)
+                    && can_push_into(g, other_side_pos.move_in_direction(d), d)
+            }
+            Direction::Left | Direction::Right => {
+                // Boxes are two wide and one tall, so pushing horizontally is
+                // easy, so simply skip two cells ahead.
+                can_push_into(g, pos.move_in_direction(d).move_in_direction(d), d)
+            }
+        },
+    }
+}
+
+fn calc_wide_gps_sum(g: &Grid<WideCell>) -> i64 {
+    g.enumerate()
+        .filter(|(_, c)| **c == WideCell::Box(BoxSide::Left))
+        .map(|(p, _)| (100 * p.y) + p.x)
+        .sum()
+}
+
+// The second warehouse is the same as the first
+// but with everything except the robot twice as wide.
+fn widen(g: &Grid<Cell>) -> Grid<WideCell> {
+    let lines = g
+        .rows()
+        .map(|y| {
+            g.cols()
+                .flat_map(|x| match g.get(x, y) {
+                    Cell::Wall => [WideCell::Wall, WideCell::Wall],
+                    Cell::Floor => [WideCell::Floor, WideCell::Floor],
+                    Cell::Robot => [WideCell::Robot, WideCell::Floor],
+                    Cell::Box => [WideCell::Box(BoxSide::Left), WideCell::Box(BoxSide::Right)],
+                })
+                .collect()
+        })
+        .collect::<Vec<_>>();
+    Grid::from_vecs(&lines)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Input;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<i64, SolveError> {
+        solve1(input)
+    }
+
+    fn part2(input: &Input) -> Result<i64, SolveError> {
+        solve2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve1() {
+        let input_str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input).unwrap();
+
+        assert_eq!(answer, 2028);
+    }
+
+    #[test]
+    fn test_solve1_2() {
+        let input_str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input).unwrap();
+
+        assert_eq!(answer, 10092);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input_str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input).unwrap();
+
+        assert_eq!(answer, 9021);
+    }
+
+    #[test]
+    fn test_no_border_wall() {
+        // Beyond the edge of the map counts as a wall.
+        let input_str = "\
+#.@O.
+
+>>>>
+";
+        let input = parse_input(input_str).unwrap();
+
+        assert_eq!(solve1(&input).unwrap(), 4);
+        assert_eq!(solve2(&input).unwrap(), 8);
+
+        let input = parse_input("..@\n\n>>v^\n").unwrap();
+
+        assert_eq!(solve1(&input).unwrap(), 0);
+        assert_eq!(solve2(&input).unwrap(), 0);
+    }
+}
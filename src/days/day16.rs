@@ -0,0 +1,233 @@
+use crate::{
+    algorithms::{astar_search, dijkstra},
+    bit_grid::BitGrid,
+    direction::Direction,
+    error::{ParseError, SolveError},
+    grid::Grid,
+    position::Position,
+    solution::Solution,
+};
+
+type State = (Position, Direction);
+
+#[derive(Debug)]
+pub struct Input {
+    grid: Grid<char>,
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let grid = s.parse()?;
+    Ok(Input { grid })
+}
+
+fn find_cell(grid: &Grid<char>, c: char) -> Result<Position, SolveError> {
+    grid.position(|x| *x == c)
+        .ok_or_else(|| SolveError::new(format!("no '{}' in the maze", c)))
+}
+
+fn solve1(input: &Input) -> Result<i64, SolveError> {
+    let start = find_cell(&input.grid, 'S')?;
+    let end = find_cell(&input.grid, 'E')?;
+    astar_search(
+        &[(start, Direction::Right)],
+        |state| get_successors(&input.grid, state),
+        |(pos, _)| *pos == end,
+        |(pos, _)| pos.manhattan_distance(&end) as i64,
+    )
+    .ok_or_else(|| SolveError::new("no path from start to end"))
+}
+
+fn get_successors(grid: &Grid<char>, state: &State) -> Vec<(State, i64)> {
+    let (pos, dir) = state;
+    let forward_pos = pos.move_in_direction(*dir);
+    let forward_cell = grid.get_pos(&forward_pos);
+    [
+        Some(((*pos, dir.rotate_cw()), 1000)),
+        Some(((*pos, dir.rotate_ccw()), 1000)),
+        (*forward_cell != '#').then_some(((forward_pos, *dir), 1)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn solve2(input: &Input) -> Result<usize, SolveError> {
+    let start = find_cell(&input.grid, 'S')?;
+    let end = find_cell(&input.grid, 'E')?;
+
+    let paths = dijkstra(
+        &[(start, Direction::Right)],
+        |state| get_successors(&input.grid, state),
+        |(pos, _)| *pos == end,
+    )
+    .ok_or_else(|| SolveError::new("no path from start to end"))?;
+
+    // Count the unique positions within the states on any shortest path.
+    let mut positions = BitGrid::new(input.grid.width, input.grid.height());
+    for (p, _) in paths.nodes_on_optimal_paths() {
+        positions.insert(p);
+    }
+    Ok(positions.count())
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Input;
+    type Answer1 = i64;
+    type Answer2 = usize;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<i64, SolveError> {
+        solve1(input)
+    }
+
+    fn part2(input: &Input) -> Result<usize, SolveError> {
+        solve2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::dijkstra_search;
+
+    #[test]
+    fn test_solve1() {
+        let input_str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input).unwrap();
+
+        assert_eq!(answer, 7036);
+    }
+
+    #[test]
+    fn test_solve1_2() {
+        let input_str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input).unwrap();
+
+        assert_eq!(answer, 11048);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input_str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input).unwrap();
+
+        assert_eq!(answer, 45);
+    }
+
+    #[test]
+    fn test_solve2_2() {
+        let input_str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input).unwrap();
+
+        assert_eq!(answer, 64);
+    }
+
+    #[test]
+    fn test_astar_matches_dijkstra() {
+        let input_str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+        let input = parse_input(input_str).unwrap();
+        let start = find_cell(&input.grid, 'S').unwrap();
+        let dijkstra = dijkstra_search(
+            &[(start, Direction::Right)],
+            |state| get_successors(&input.grid, state),
+            |(pos, _)| *input.grid.get_pos(pos) == 'E',
+        );
+
+        assert_eq!(solve1(&input).ok(), dijkstra);
+    }
+}
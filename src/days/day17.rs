@@ -0,0 +1,299 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{
+    error::{parse_field, sections, ParseError, SolveError},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Input {
+    vm: (i64, i64, i64),
+    program: Vec<u8>,
+}
+
+lazy_static! {
+    static ref REGISTER_A_REGEX: Regex = Regex::new(r"^Register A: (\d+)$").unwrap();
+    static ref REGISTER_B_REGEX: Regex = Regex::new(r"^Register B: (\d+)$").unwrap();
+    static ref REGISTER_C_REGEX: Regex = Regex::new(r"^Register C: (\d+)$").unwrap();
+    static ref PROGRAM_REGEX: Regex = Regex::new(r"^Program: (\d(?:,\d)+)$").unwrap();
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let sections = sections(s);
+    let [registers, program] = sections.as_slice() else {
+        return Err(ParseError::new(
+            "expected registers and a program separated by a blank line",
+        ));
+    };
+    let [reg_a, reg_b, reg_c] = registers.as_slice() else {
+        return Err(ParseError::new("expected three registers").at_line(registers[0].0));
+    };
+    let [program] = program.as_slice() else {
+        return Err(ParseError::new("expected a single program line").at_line(program[0].0));
+    };
+
+    let (n, l) = *program;
+    let captures = PROGRAM_REGEX.captures(l).ok_or_else(|| {
+        ParseError::invalid("expected a line like Program: 0,1,5,4", l).at_line(n)
+    })?;
+    let program = captures[1]
+        .split(',')
+        .map(|x| parse_field(l, x))
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.at_line(n))?;
+
+    Ok(Input {
+        vm: (
+            parse_register(*reg_a, &REGISTER_A_REGEX, 'A')?,
+            parse_register(*reg_b, &REGISTER_B_REGEX, 'B')?,
+            parse_register(*reg_c, &REGISTER_C_REGEX, 'C')?,
+        ),
+        program,
+    })
+}
+
+fn parse_register((n, l): (usize, &str), regex: &Regex, name: char) -> Result<i64, ParseError> {
+    let captures = regex.captures(l).ok_or_else(|| {
+        ParseError::invalid(format!("expected a line like Register {}: 0", name), l).at_line(n)
+    })?;
+    parse_field(l, &captures[1]).map_err(|e| e.at_line(n))
+}
+
+struct VmState {
+    a: i64,
+    b: i64,
+    c: i64,
+    pc: usize,
+}
+
+impl VmState {
+    fn get_combo(&self, operand: u8) -> Result<i64, SolveError> {
+        match operand {
+            0..=3 => Ok(operand.into()),
+            4 => Ok(self.a),
+            5 => Ok(self.b),
+            6 => Ok(self.c),
+            _ => Err(SolveError::new(format!(
+                "illegal combo operand {} at {}",
+                operand,
+                self.pc - 1
+            ))),
+        }
+    }
+
+    // The adv, bdv and cdv instructions divide register A by 2 to the power of the combo operand.
+    fn divide_a(&self, operand: u8) -> Result<i64, SolveError> {
+        let power = self.get_combo(operand)?;
+        u32::try_from(power)
+            .ok()
+            .and_then(|power| 2i64.checked_pow(power))
+            .map(|divisor| self.a / divisor)
+            .ok_or_else(|| {
+                SolveError::new(format!(
+                    "cannot divide by 2 to the power of {} at {}",
+                    power,
+                    self.pc - 2
+                ))
+            })
+    }
+}
+
+fn solve1(input: &Input) -> Result<String, SolveError> {
+    let mut vm = VmState {
+        a: input.vm.0,
+        b: input.vm.1,
+        c: input.vm.2,
+        pc: 0,
+    };
+    let mut output = Vec::<u8>::new();
+    let code = &input.program;
+
+    while vm.pc + 1 < code.len() {
+        // decode and execute instruction
+        let op = code[vm.pc];
+        let operand = code[vm.pc + 1];
+        vm.pc += 2;
+
+        match op {
+            // adv
+            0 => {
+                vm.a = vm.divide_a(operand)?;
+            }
+            // bxl
+            1 => {
+                vm.b ^= i64::from(operand);
+            }
+            // bst
+            2 => {
+                vm.b = vm.get_combo(operand)? % 8;
+            }
+            // jnz
+            3 => {
+                if vm.a != 0 {
+                    vm.pc = operand.into();
+                }
+            }
+            // bxc
+            4 => {
+                vm.b ^= vm.c;
+            }
+            // out
+            5 => {
+                let val = vm.get_combo(operand)? % 8;
+                output.push(val as u8);
+            }
+            // bdv
+            6 => {
+                vm.b = vm.divide_a(operand)?;
+            }
+            // cdv
+            7 => {
+                vm.c = vm.divide_a(operand)?;
+            }
+            _ => {
+                return Err(SolveError::new(format!(
+                    "illegal opcode {} at {}",
+                    op,
+                    vm.pc - 2
+                )))
+            }
+        }
+    }
+
+    Ok(output
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .join(","))
+}
+
+fn solve2(input: &Input) -> Result<i64, SolveError> {
+    // hand-written disassembly of the VM code:
+    //
+    // bst 'a    // b = a % 8
+    // bxl 5     // b ^= 5
+    // cdv 'b    // c = a / (2**b)
+    // bxl 6     // b ^= 6
+    // bxc       // b ^= c
+    // out 'b    // print b
+    // adv 3     // a /= 8
+    // jnz 0     // if a != 0 then goto start
+    //
+    // So we are basically consuming the last 3 bits of 'a,
+    // doing some bit twiddling on it and outputting it,
+    // repeatedly in a loop.
+    // We can go backwards then and figure out how to build up 'a
+    // to build up the output we want, starting from the last output.
+    find_reg_val(&input.program, 0)
+        .ok_or_else(|| SolveError::new("no value of register A makes the program output itself"))
+}
+
+fn find_reg_val(outs: &[u8], final_reg_val: i64) -> Option<i64> {
+    if outs.is_empty() {
+        return Some(final_reg_val);
+    }
+
+    let out = outs[outs.len() - 1];
+
+    let mult_reg = final_reg_val * 8;
+    (0..8)
+        .map(|i| mult_reg + i)
+        .filter(|i| *i != 0) // can't be 0 because loop terminates when it's 0
+        .filter(|r| compute_out(*r) == out)
+        .find_map(|new_r| find_reg_val(&outs[..(outs.len() - 1)], new_r))
+}
+
+fn compute_out(a: i64) -> u8 {
+    let mut b = a % 8;
+    b ^= 5;
+    let c = a / 2i64.pow(b.try_into().unwrap());
+    b ^= 6;
+    b ^= c;
+    (b % 8).try_into().unwrap()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Input;
+    type Answer1 = String;
+    type Answer2 = i64;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<String, SolveError> {
+        solve1(input)
+    }
+
+    fn part2(input: &Input) -> Result<i64, SolveError> {
+        solve2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve1() {
+        let input_str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input).unwrap();
+
+        assert_eq!(answer, "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn test_solve1_illegal_combo_operand() {
+        let input_str = "\
+Register A: 0
+Register B: 0
+Register C: 0
+
+Program: 5,7
+";
+        let input = parse_input(input_str).unwrap();
+        let err = solve1(&input).unwrap_err();
+
+        assert_eq!(err.message, "illegal combo operand 7 at 1");
+    }
+
+    #[test]
+    fn test_solve1_divisor_too_large() {
+        let input_str = "\
+Register A: 100
+Register B: 0
+Register C: 0
+
+Program: 0,4,5,4
+";
+        let input = parse_input(input_str).unwrap();
+        let err = solve1(&input).unwrap_err();
+
+        assert_eq!(err.message, "cannot divide by 2 to the power of 100 at 0");
+
+        let input_str = "\
+Register A: 5000000000
+Register B: 0
+Register C: 0
+
+Program: 0,4,5,4
+";
+        let input = parse_input(input_str).unwrap();
+        let err = solve1(&input).unwrap_err();
+
+        assert_eq!(
+            err.message,
+            "cannot divide by 2 to the power of 5000000000 at 0"
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use crate::{
+    algorithms::{grid_bfs, IndexDisjointSet},
+    error::{parse_field, parse_lines, ParseError, SolveError},
+    grid::Grid,
+    position::{pos, Position},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Input {
+    coords: Vec<Position>,
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let coords = parse_lines(s, |l| {
+        let (x, y) = l
+            .split_once(',')
+            .ok_or_else(|| ParseError::invalid("expected coordinates like 5,4", l))?;
+        Ok(Position::new(parse_field(l, x)?, parse_field(l, y)?))
+    })?;
+    Ok(Input { coords })
+}
+
+fn solve1(input: &Input, w: i64, h: i64, n: usize) -> Result<i64, SolveError> {
+    let obstacles = input
+        .coords
+        .get(0..n)
+        .ok_or_else(|| SolveError::new(format!("expected at least {} bytes", n)))?;
+    shortest_path(w, h, obstacles).ok_or_else(|| SolveError::new("the exit is unreachable"))
+}
+
+fn solve2(input: &Input, w: i64, h: i64) -> Result<String, SolveError> {
+    // Drop every byte, then lift them again latest first,
+    // joining each cell that comes free to its free neighbours.
+    // The byte whose removal first joins the start to the exit
+    // is the one that cut them off.
+    let mut first_byte = Grid::new(w as usize, h as usize, None);
+    for (i, c) in input.coords.iter().enumerate().rev() {
+        if first_byte.is_in_bounds(c) {
+            first_byte.set_pos(c, Some(i));
+        }
+    }
+
+    let mut sets = IndexDisjointSet::new(first_byte.vec.len());
+    for p in first_byte.pos_iter() {
+        if first_byte.get_pos(&p).is_none() {
+            join_free_neighbours(&mut sets, &first_byte, p);
+        }
+    }
+
+    let start = 0;
+    let exit = first_byte.vec.len() - 1;
+    if sets.connected(start, exit) {
+        return Err(SolveError::new("the exit is never blocked"));
+    }
+
+    for (i, c) in input.coords.iter().enumerate().rev() {
+        if first_byte.try_get_pos(c) == Some(&Some(i)) {
+            first_byte.set_pos(c, None);
+            join_free_neighbours(&mut sets, &first_byte, *c);
+            if sets.connected(start, exit) {
+                return Ok(format!("{},{}", c.x, c.y));
+            }
+        }
+    }
+
+    Err(SolveError::new("the exit is unreachable"))
+}
+
+fn join_free_neighbours(sets: &mut IndexDisjointSet, grid: &Grid<Option<usize>>, p: Position) {
+    let i = grid.pos_to_vec_index(&p).unwrap();
+    for (n, byte) in grid.neighbours(p) {
+        if byte.is_none() {
+            sets.union(i, grid.pos_to_vec_index(&n).unwrap());
+        }
+    }
+}
+
+fn shortest_path(w: i64, h: i64, obstacles_list: &[Position]) -> Option<i64> {
+    let mut blocked = Grid::new(w as usize, h as usize, false);
+    for c in obstacles_list {
+        if blocked.is_in_bounds(c) {
+            blocked.set_pos(c, true);
+        }
+    }
+    let distances = grid_bfs(&blocked, &[pos(0, 0)], |_, to| !to);
+    distances.get_pos(&pos(w - 1, h - 1)).map(i64::from)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Input;
+    type Answer1 = i64;
+    type Answer2 = String;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<i64, SolveError> {
+        solve1(input, 71, 71, 1024)
+    }
+
+    fn part2(input: &Input) -> Result<String, SolveError> {
+        solve2(input, 71, 71)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::{
+        algorithms::{astar_search, dijkstra_search},
+        direction::DIRECTIONS,
+    };
+
+    fn get_successors(
+        w: i64,
+        h: i64,
+        obstacles: &HashSet<Position>,
+        c: &Position,
+    ) -> Vec<(Position, i64)> {
+        DIRECTIONS
+            .iter()
+            .map(|d| c.move_in_direction(*d))
+            .filter(|c| (0..w).contains(&c.x) && (0..h).contains(&c.y) && !obstacles.contains(c))
+            .map(|c| (c, 1))
+            .collect()
+    }
+
+    #[test]
+    fn test_solve1() {
+        let input_str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input, 7, 7, 12).unwrap();
+
+        assert_eq!(answer, 22);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input_str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input, 7, 7).unwrap();
+
+        assert_eq!(answer, "6,1");
+    }
+
+    #[test]
+    fn test_astar_matches_dijkstra() {
+        let input_str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+        let input = parse_input(input_str).unwrap();
+        let exit = pos(6, 6);
+        for n in 0..=input.coords.len() {
+            let obstacles: HashSet<_> = input.coords[0..n].iter().copied().collect();
+            let successors = |c: &Position| get_successors(7, 7, &obstacles, c);
+            let dijkstra = dijkstra_search(&[pos(0, 0)], successors, |c| *c == exit);
+            let astar = astar_search(
+                &[pos(0, 0)],
+                successors,
+                |c| *c == exit,
+                |c| c.manhattan_distance(&exit) as i64,
+            );
+            assert_eq!(astar, dijkstra);
+            assert_eq!(shortest_path(7, 7, &input.coords[0..n]), dijkstra);
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{
+    error::{ParseError, SolveError},
+    memo::Memo,
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Input {
+    towels: Vec<String>,
+    designs: Vec<String>,
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let mut lines = s.lines();
+    let towels = lines
+        .next()
+        .ok_or_else(|| ParseError::new("empty input"))?
+        .split(", ")
+        .map(|x| x.to_owned())
+        .collect::<Vec<_>>();
+    match lines.next() {
+        Some("") => {}
+        Some(l) => return Err(ParseError::invalid("expected a blank line", l).at_line(2)),
+        None => return Err(ParseError::new("missing designs").at_line(2)),
+    }
+    let designs = lines.map(|l| l.to_owned()).collect::<Vec<_>>();
+    Ok(Input { towels, designs })
+}
+
+fn solve1(input: &Input) -> usize {
+    let mut is_possible = Memo::new(|memo: &mut Memo<&str, bool>, d: &str| {
+        d.is_empty()
+            || input
+                .towels
+                .iter()
+                .filter_map(|t| d.strip_prefix(t.as_str()))
+                .any(|d| memo.get(d))
+    });
+    input.designs.iter().filter(|d| is_possible.get(d)).count()
+}
+
+fn solve2(input: &Input) -> usize {
+    let mut count_ways = Memo::new(|memo: &mut Memo<&str, usize>, d: &str| {
+        if d.is_empty() {
+            return 1;
+        }
+        input
+            .towels
+            .iter()
+            .filter_map(|t| d.strip_prefix(t.as_str()))
+            .map(|d| memo.get(d))
+            .sum()
+    });
+    input.designs.iter().map(|d| count_ways.get(d)).sum()
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<usize, SolveError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: &Input) -> Result<usize, SolveError> {
+        Ok(solve2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve1() {
+        let input_str = "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input);
+
+        assert_eq!(answer, 6);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input_str = "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input);
+
+        assert_eq!(answer, 16);
+    }
+}
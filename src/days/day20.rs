@@ -0,0 +1,265 @@
+use std::iter;
+
+use crate::{
+    algorithms::{bfs_until, grid_bfs},
+    direction::DIRECTIONS,
+    error::{ParseError, SolveError},
+    grid::Grid,
+    position::Position,
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Input {
+    grid: Grid<Cell>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Wall,
+    Floor,
+    Start,
+    End,
+}
+
+fn parse_char(c: char) -> Option<Cell> {
+    match c {
+        '#' => Some(Cell::Wall),
+        '.' => Some(Cell::Floor),
+        'S' => Some(Cell::Start),
+        'E' => Some(Cell::End),
+        _ => None,
+    }
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let grid = Grid::parse_with(s, parse_char)?;
+    Ok(Input { grid })
+}
+
+fn solve1(input: &Input) -> Result<usize, SolveError> {
+    Ok(enumerate_bridges(input)?.filter(|(i, _)| *i >= 100).count())
+}
+
+fn find_bridges(grid: &Grid<Cell>, p: Position) -> impl Iterator<Item = Position> + '_ {
+    DIRECTIONS.iter().filter_map(move |d| {
+        let it = iter::successors(Some(p), |&p| Some(p.move_in_direction(*d)));
+        it.skip(1)
+            .take(2)
+            .find(|p| grid.try_get_pos(p).is_some_and(|c| *c != Cell::Wall))
+    })
+}
+
+fn find_start(grid: &Grid<Cell>) -> Result<Position, SolveError> {
+    grid.position(|c| *c == Cell::Start)
+        .ok_or_else(|| SolveError::new("no start in the map"))
+}
+
+fn enumerate_bridges(
+    input: &Input,
+) -> Result<impl Iterator<Item = (usize, (Position, Position))> + '_, SolveError> {
+    let start = find_start(&input.grid)?;
+
+    let path = grid_bfs(&input.grid, &[start], |_, to| *to != Cell::Wall);
+
+    Ok(input
+        .grid
+        .enumerate()
+        .filter(|(_, c)| match c {
+            Cell::Wall => false,
+            Cell::Floor => true,
+            Cell::Start => true,
+            Cell::End => false,
+        })
+        .flat_map(|(p, _)| find_bridges(&input.grid, p).map(move |b| (p, b)))
+        .filter_map(move |(start, end)| {
+            let start_step = (*path.get_pos(&start))? as usize;
+            let end_step = (*path.get_pos(&end))? as usize;
+            let bridge_distance = start.manhattan_distance(&end) as usize;
+            if end_step <= start_step {
+                return None;
+            }
+            let skipped_steps = end_step - start_step;
+            if skipped_steps <= bridge_distance {
+                return None;
+            }
+            Some((skipped_steps - bridge_distance, (start, end)))
+        }))
+}
+
+fn solve2(input: &Input) -> Result<usize, SolveError> {
+    let path = get_path(&input.grid)?;
+    Ok(enumerate_cheats(&path, 20)
+        .filter(|(i, _)| *i >= 100)
+        .count())
+}
+
+fn enumerate_cheats(
+    path_vec: &[Position],
+    n: usize,
+) -> impl Iterator<Item = (usize, (Position, Position))> + '_ {
+    // Cheats must start and end on the path so we'll look at all pairs
+    // of points on the path and keep only those that are viable cheats.
+    iter_pairs_with_positions(path_vec).filter_map(move |((i, &p1), (j, &p2))| {
+        let bridge_distance = p1.manhattan_distance(&p2) as usize;
+        if bridge_distance > n {
+            return None;
+        }
+        let skipped_steps = j - i;
+        if skipped_steps <= bridge_distance {
+            return None;
+        }
+        Some((skipped_steps - bridge_distance, (p1, p2)))
+    })
+}
+
+/// Iterates forward pairs with their positions in the slice.
+/// i.e. for the slice [a,b,c], yields ((0,a),(1,b)), ((0,a),(2,c)), ((1,b),(2,c))
+fn iter_pairs_with_positions<T>(slice: &[T]) -> impl Iterator<Item = ((usize, &T), (usize, &T))> {
+    slice.iter().enumerate().flat_map(|(i, p1)| {
+        slice[(i + 1)..]
+            .iter()
+            .enumerate()
+            .map(move |(j, p2)| ((i, p1), (i + j + 1, p2)))
+    })
+}
+
+fn get_path(grid: &Grid<Cell>) -> Result<Vec<Position>, SolveError> {
+    let start = find_start(grid)?;
+
+    let end = grid
+        .position(|c| *c == Cell::End)
+        .ok_or_else(|| SolveError::new("no end in the map"))?;
+
+    bfs_until(
+        &[start],
+        |p| {
+            grid.neighbours(*p)
+                .filter(|(_, c)| **c != Cell::Wall)
+                .map(|(p, _)| p)
+                .collect::<Vec<_>>()
+        },
+        |p| *p == end,
+    )
+    .target_path()
+    .ok_or_else(|| SolveError::new("the end is unreachable"))
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<usize, SolveError> {
+        solve1(input)
+    }
+
+    fn part2(input: &Input) -> Result<usize, SolveError> {
+        solve2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn test_solve1() {
+        let input_str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+        let input = parse_input(input_str).unwrap();
+
+        let mut cheat_counts = HashMap::new();
+        for (i, _) in enumerate_bridges(&input).unwrap() {
+            cheat_counts
+                .entry(i)
+                .and_modify(|e| {
+                    *e += 1;
+                })
+                .or_insert(1);
+        }
+
+        assert_eq!(cheat_counts[&2], 14);
+        assert_eq!(cheat_counts[&4], 14);
+        assert_eq!(cheat_counts[&6], 2);
+        assert_eq!(cheat_counts[&8], 4);
+        assert_eq!(cheat_counts[&10], 2);
+        assert_eq!(cheat_counts[&12], 3);
+        assert_eq!(cheat_counts[&20], 1);
+        assert_eq!(cheat_counts[&36], 1);
+        assert_eq!(cheat_counts[&38], 1);
+        assert_eq!(cheat_counts[&40], 1);
+        assert_eq!(cheat_counts[&64], 1);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input_str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+        let input = parse_input(input_str).unwrap();
+
+        let mut cheat_counts = HashMap::new();
+        let path = get_path(&input.grid).unwrap();
+        for (i, _) in enumerate_cheats(&path, 20) {
+            cheat_counts
+                .entry(i)
+                .and_modify(|e| {
+                    *e += 1;
+                })
+                .or_insert(1);
+        }
+
+        assert_eq!(cheat_counts[&50], 32);
+        assert_eq!(cheat_counts[&52], 31);
+        assert_eq!(cheat_counts[&54], 29);
+        assert_eq!(cheat_counts[&56], 39);
+        assert_eq!(cheat_counts[&58], 25);
+        assert_eq!(cheat_counts[&60], 23);
+        assert_eq!(cheat_counts[&62], 20);
+        assert_eq!(cheat_counts[&64], 19);
+        assert_eq!(cheat_counts[&66], 12);
+        assert_eq!(cheat_counts[&68], 14);
+        assert_eq!(cheat_counts[&70], 12);
+        assert_eq!(cheat_counts[&72], 22);
+        assert_eq!(cheat_counts[&74], 4);
+        assert_eq!(cheat_counts[&76], 3);
+    }
+}
@@ -1,20 +1,90 @@
-use std::{
-    collections::HashMap,
-    fs::{self},
-    iter,
-    time::Instant,
-};
+use std::iter;
 
-use aoc24::position::{pos, Position};
+use crate::{
+    error::{parse_chars, parse_lines, ParseError, SolveError},
+    memo::Memo,
+    position::{pos, Position},
+    solution::Solution,
+};
 
 #[derive(Debug)]
-struct Input {
+pub struct Input {
     lines: Vec<String>,
 }
 
-fn parse_input(s: &str) -> Input {
-    let lines = s.lines().map(|l| l.to_string()).collect::<Vec<_>>();
-    Input { lines }
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    // Codes are checked here so that the solvers can assume
+    // every character is a key on the numeric keypad.
+    let lines = parse_lines(s, |l| {
+        parse_chars(l, |c| matches!(c, '0'..='9' | 'A').then_some(c))?;
+        match l.strip_suffix('A') {
+            Some(digits) if !digits.is_empty() && !digits.contains('A') => Ok(l.to_string()),
+            _ => Err(ParseError::invalid("expected a code like 029A", l)),
+        }
+    })?;
+    Ok(Input { lines })
+}
+
+fn solve1(input: &Input) -> usize {
+    // Observations:
+    //
+    // - When we type a digit, each directional keypad always starts on 'A'.
+    //   Only the digit keypad persists state between digit inputs.
+    //
+    // - Changing input is costly so it's always better to repeat inputs
+    //   as much as possible. Therefore there are only max. two ways to
+    //   move from one keypad input to another. One of these ways MAY be
+    //   illegal due to passing over the illegal square.
+    //
+    // - It seems some paths are more costly than others due to the knock-on
+    //   effect on higher-level encodings. It's not obvious to me what the rule
+    //   is for this yet.
+    input
+        .lines
+        .iter()
+        .map(|l| (l, encode(l)))
+        .map(|(code, sequence)| get_complexity(code, sequence.len()))
+        .sum()
+}
+
+fn encode(code: &str) -> String {
+    code.chars()
+        .map(position_of_num_key)
+        .scan(position_of_num_key('A'), |pos, next_pos| {
+            let result = encode_move_and_press(*pos, next_pos);
+            *pos = next_pos;
+            Some(result)
+        })
+        .collect()
+}
+
+fn encode_move_and_press(start: Position, end: Position) -> String {
+    encodings_for_num_press(start, end)
+        .into_iter()
+        .flat_map(|seq| encodings_for_dir_seq(&seq))
+        .flat_map(|seq| encodings_for_dir_seq(&seq))
+        .min_by_key(|s| s.len())
+        .unwrap()
+}
+
+fn encodings_for_dir_seq(seq: &str) -> Vec<String> {
+    encodings_for_dir_seq_inner(position_of_dir_key('A'), seq)
+}
+
+fn encodings_for_dir_seq_inner(start_pos: Position, seq: &str) -> Vec<String> {
+    let Some(c) = seq.chars().next() else {
+        return vec![String::new()];
+    };
+    let next_pos = position_of_dir_key(c);
+    let encoded_seqs = encodings_for_dir_press(start_pos, next_pos);
+    encoded_seqs
+        .into_iter()
+        .flat_map(|s| {
+            encodings_for_dir_seq_inner(next_pos, &seq[1..])
+                .into_iter()
+                .map(move |next| s.clone() + &next)
+        })
+        .collect()
 }
 
 /*
@@ -60,7 +130,7 @@ Repeating it allowed us to skip the cost of visiting it for the second time
 which was beneficial overall to the length of the level 2 encoding.
 */
 
-fn solve(input: &Input, depth: usize) -> usize {
+fn solve2(input: &Input, depth: usize) -> usize {
     // Observations:
     //
     // - When we type a digit, each directional keypad always starts on 'A'.
@@ -82,88 +152,56 @@ fn solve(input: &Input, depth: usize) -> usize {
     // To solve this we will find the encoding with the overall least costly key
     // transitions. We can memoize the cost of a transition from one key to another
     // at a given "encoding depth" to speed things up.
-    let mut context = Context::new();
+    let mut transition_cost = Memo::new(
+        |memo: &mut TransitionCost, (prev, next, depth): (char, char, usize)| {
+            if depth == 0 || prev == next {
+                return 1;
+            }
+            let prev_pos = position_of_dir_key(prev);
+            let next_pos = position_of_dir_key(next);
+            encodings_for_dir_press(prev_pos, next_pos)
+                .iter()
+                .map(|enc| encoding_cost(memo, enc, depth - 1))
+                .min()
+                .unwrap()
+        },
+    );
 
     input
         .lines
         .iter()
-        .map(|l| (l, context.get_encoding_len(l, depth)))
+        .map(|l| (l, get_encoding_len(&mut transition_cost, l, depth)))
         .map(|(code, sequence_len)| get_complexity(code, sequence_len))
         .sum()
 }
 
-struct Context {
-    cache: HashMap<(char, char, usize), usize>,
+// The cost of pressing the key `next` after the key `prev`
+// on a directional keypad at a given encoding depth.
+type TransitionCost<'a> = Memo<'a, (char, char, usize), usize>;
+
+fn get_encoding_len(memo: &mut TransitionCost, code: &str, depth: usize) -> usize {
+    code.chars()
+        .map(position_of_num_key)
+        .scan(position_of_num_key('A'), |pos, next_pos| {
+            let result = encodings_for_num_press(*pos, next_pos)
+                .into_iter()
+                .map(|seq| encoding_cost(memo, &seq, depth))
+                .min()
+                .unwrap();
+            *pos = next_pos;
+            Some(result)
+        })
+        .sum()
 }
 
-impl Context {
-    fn new() -> Self {
-        Self {
-            cache: HashMap::new(),
-        }
-    }
-
-    fn get_encoding_len(&mut self, code: &str, depth: usize) -> usize {
-        code.chars()
-            .map(position_of_num_key)
-            .scan(position_of_num_key('A'), |pos, next_pos| {
-                let result = self.get_move_and_press_encoding_len(*pos, next_pos, depth);
-                *pos = next_pos;
-                Some(result)
-            })
-            .sum()
-    }
-
-    fn get_move_and_press_encoding_len(
-        &mut self,
-        start: Position,
-        end: Position,
-        depth: usize,
-    ) -> usize {
-        encodings_for_num_press(start, end)
-            .into_iter()
-            .map(|seq| self.compute_encoding_cost(&seq, depth))
-            .min()
-            .unwrap()
-    }
-
-    fn compute_encoding_cost(&mut self, seq: &str, depth: usize) -> usize {
-        seq.chars()
-            .scan('A', |prev, next| {
-                let cost = self.get_transition_cost(*prev, next, depth);
-                *prev = next;
-                Some(cost)
-            })
-            .sum()
-    }
-
-    fn get_transition_cost(&mut self, prev: char, next: char, depth: usize) -> usize {
-        if depth == 0 {
-            return 1;
-        }
-        if prev == next {
-            return 1;
-        }
-
-        if let Some(cost) = self.cache.get(&(prev, next, depth)) {
-            return *cost;
-        }
-
-        let prev_pos = position_of_dir_key(prev);
-        let next_pos = position_of_dir_key(next);
-
-        let possible_encodings = encodings_for_dir_press(prev_pos, next_pos);
-
-        let cost = possible_encodings
-            .iter()
-            .map(|enc| self.compute_encoding_cost(enc, depth - 1))
-            .min()
-            .unwrap();
-
-        self.cache.insert((prev, next, depth), cost);
-
-        cost
-    }
+fn encoding_cost(memo: &mut TransitionCost, seq: &str, depth: usize) -> usize {
+    seq.chars()
+        .scan('A', |prev, next| {
+            let cost = memo.get((*prev, next, depth));
+            *prev = next;
+            Some(cost)
+        })
+        .sum()
 }
 
 fn encodings_for_num_press(start: Position, end: Position) -> Vec<String> {
@@ -288,15 +326,24 @@ fn get_numeric_part(code: &str) -> usize {
     code[0..code.len() - 1].parse().unwrap()
 }
 
-fn main() {
-    let start_time = Instant::now();
-    let input_str = fs::read_to_string("data/day21/input").unwrap();
-    let input = parse_input(&input_str);
+pub struct Day21;
 
-    let answer = solve(&input, 25);
+impl Solution for Day21 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    println!("Answer: {}", answer);
-    println!("Elapsed time: {}us", start_time.elapsed().as_micros());
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<usize, SolveError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: &Input) -> Result<usize, SolveError> {
+        Ok(solve2(input, 25))
+    }
 }
 
 #[cfg(test)]
@@ -312,8 +359,23 @@ mod tests {
 456A
 379A
 ";
-        let input = parse_input(&input_str);
-        let answer = solve(&input, 2);
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input);
+
+        assert_eq!(answer, 126384);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input_str = "\
+029A
+980A
+179A
+456A
+379A
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input, 2);
 
         assert_eq!(answer, 126384);
     }
@@ -341,4 +403,18 @@ mod tests {
             vec!["v<<A"]
         );
     }
+
+    #[test]
+    fn test_encodings_for_dir_seq_inner() {
+        assert_eq!(
+            encodings_for_dir_seq_inner(position_of_dir_key('A'), "A"),
+            vec!["A"]
+        );
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(encode("A"), "A");
+        assert_eq!(encode("0"), "<vA<AA>>^AvAA<^A>A");
+    }
 }
@@ -0,0 +1,175 @@
+use std::{collections::HashMap, iter};
+
+use crate::{
+    error::{parse_lines, parse_str, ParseError, SolveError},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Input {
+    lines: Vec<u64>,
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let lines = parse_lines(s, parse_str)?;
+    Ok(Input { lines })
+}
+
+fn solve1(input: &Input) -> u64 {
+    input
+        .lines
+        .iter()
+        .map(|l| (0..2000).fold(*l, |n, _| next_num(n)))
+        .sum()
+}
+
+fn iter_secret_nums(l: u64) -> impl Iterator<Item = u64> {
+    iter::successors(Some(l), |n| Some(next_num(*n)))
+}
+
+fn next_num(mut n: u64) -> u64 {
+    n = prune(mix(n, n * 64));
+    n = prune(mix(n, n / 32));
+    n = prune(mix(n, n * 2048));
+    n
+}
+
+fn mix(a: u64, b: u64) -> u64 {
+    a ^ b
+}
+
+fn prune(n: u64) -> u64 {
+    n % 16777216
+}
+
+fn solve2(input: &Input) -> Result<u64, SolveError> {
+    let mut master_lookup = HashMap::new();
+    for l in &input.lines {
+        for (k, v) in make_code_lookup(*l)? {
+            master_lookup.entry(k).and_modify(|x| *x += v).or_insert(v);
+        }
+    }
+
+    master_lookup
+        .values()
+        .copied()
+        .max()
+        .ok_or_else(|| SolveError::new("no buyers in the input"))
+}
+
+type Code = (i64, i64, i64, i64);
+fn next_code(c: Code, val: i64) -> Code {
+    (c.1, c.2, c.3, val)
+}
+
+fn make_code_lookup(l: u64) -> Result<HashMap<Code, u64>, SolveError> {
+    let mut price_and_delta_it =
+        iter_secret_nums(l)
+            .skip(1)
+            .take(2000)
+            .map(|v| v % 10)
+            .scan(l, |prev, curr| {
+                let delta = (curr as i64) - (*prev as i64);
+                *prev = curr;
+                Some((curr, delta))
+            });
+
+    let (Some(p1), Some(p2), Some(p3)) = (
+        price_and_delta_it.next(),
+        price_and_delta_it.next(),
+        price_and_delta_it.next(),
+    ) else {
+        return Err(SolveError::new(
+            "too few prices to make a sequence of four changes",
+        ));
+    };
+
+    let mut lookup = HashMap::new();
+    price_and_delta_it
+        .scan((0i64, p1.1, p2.1, p3.1), |prev_code, (price, delta)| {
+            let code = next_code(*prev_code, delta);
+            *prev_code = code;
+            Some((code, price))
+        })
+        .for_each(|(k, v)| {
+            lookup.entry(k).or_insert(v);
+        });
+    Ok(lookup)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Input;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<u64, SolveError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: &Input) -> Result<u64, SolveError> {
+        solve2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve1() {
+        let input_str = "\
+1
+10
+100
+2024
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input);
+
+        assert_eq!(answer, 37327623);
+    }
+
+    #[test]
+    fn test_iter_secret_nums() {
+        let initial = 123;
+        let v = iter_secret_nums(initial)
+            .skip(1)
+            .take(10)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            v,
+            vec![
+                15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432,
+                5908254,
+            ]
+        )
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input_str = "\
+1
+2
+3
+2024
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input).unwrap();
+
+        assert_eq!(answer, 23);
+    }
+
+    #[test]
+    fn test_solve2_no_buyers() {
+        let input = parse_input("").unwrap();
+        let err = solve2(&input).unwrap_err();
+
+        assert_eq!(err.message, "no buyers in the input");
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{
+    error::{ParseError, SolveError},
+    graph::{k_cliques, maximum_clique, Graph},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Input {
+    network: Graph<String>,
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let network = Graph::undirected().parse_edges(s, "-")?;
+    Ok(Input { network })
+}
+
+fn solve1(input: &Input) -> usize {
+    k_cliques(&input.network, 3)
+        .into_iter()
+        .filter(|c| c.iter().any(|&n| input.network.node(n).starts_with('t')))
+        .count()
+}
+
+fn solve2(input: &Input) -> String {
+    // The largest set of computers that are all connected to each other
+    // is the maximum clique of the network.
+    let mut names = maximum_clique(&input.network)
+        .into_iter()
+        .map(|n| input.network.node(n).as_str())
+        .collect::<Vec<_>>();
+    names.sort();
+    names.join(",")
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<usize, SolveError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: &Input) -> Result<String, SolveError> {
+        Ok(solve2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve1() {
+        let input_str = "\
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input);
+
+        assert_eq!(answer, 7);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let input_str = "\
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve2(&input);
+
+        assert_eq!(answer, "co,de,ka,ta");
+    }
+}
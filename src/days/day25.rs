@@ -0,0 +1,135 @@
+use crate::{
+    error::{sections, ParseError, SolveError},
+    grid::Grid,
+    solution::{Solution, Unsolved},
+};
+
+#[derive(Debug)]
+pub struct Input {
+    locks: Vec<Vec<usize>>,
+    keys: Vec<Vec<usize>>,
+}
+
+fn parse_input(s: &str) -> Result<Input, ParseError> {
+    let mut locks = Vec::new();
+    let mut keys = Vec::new();
+    for section in sections(s) {
+        let g = Grid::parse_section(&section, Some)?;
+        let heights = heights(&g).map_err(|e| e.at_line(section[0].0))?;
+        if g.row(0).iter().all(|&ch| ch == '#') {
+            // top all filled in, it's a lock
+            locks.push(heights);
+        } else {
+            // otherwise it's a key
+            keys.push(heights);
+        }
+    }
+
+    Ok(Input { locks, keys })
+}
+
+// Each column holds one '#' in the full top or bottom row plus one per unit of height.
+fn heights(g: &Grid<char>) -> Result<Vec<usize>, ParseError> {
+    g.iter_cols()
+        .enumerate()
+        .map(|(x, c)| {
+            c.filter(|&&ch| ch == '#')
+                .count()
+                .checked_sub(1)
+                .ok_or_else(|| ParseError::new("column has no '#'").at_column(x + 1))
+        })
+        .collect()
+}
+
+fn solve1(input: &Input) -> usize {
+    input
+        .locks
+        .iter()
+        .map(|l| input.keys.iter().filter(|k| lock_key_matches(l, k)).count())
+        .sum()
+}
+
+fn lock_key_matches(l: &[usize], k: &[usize]) -> bool {
+    l.iter().zip(k).all(|(a, b)| (*a + *b) <= 5)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = Unsolved;
+
+    fn parse(s: &str) -> Result<Input, ParseError> {
+        parse_input(s)
+    }
+
+    fn part1(input: &Input) -> Result<usize, SolveError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(_input: &Input) -> Result<Unsolved, SolveError> {
+        // There is no part 2 puzzle on the final day.
+        Ok(Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve1() {
+        let input_str = "\
+#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####
+";
+        let input = parse_input(input_str).unwrap();
+        let answer = solve1(&input);
+
+        assert_eq!(answer, 3);
+    }
+
+    #[test]
+    fn test_empty_column() {
+        let err = parse_input("#####\n####.\n\n.....\n##.##\n.....\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 4, column 3: column has no '#'");
+    }
+}
@@ -0,0 +1,120 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use crate::{
+    bench::{bench_part1, bench_part2, BenchConfig, PartBench},
+    error::Error,
+    solution::{run_part1, run_part2, Solution},
+};
+
+/// A single puzzle solver: one part of one day.
+pub struct Solver {
+    pub day: u32,
+    pub part: u32,
+    pub run: fn(&str) -> Result<String, Error>,
+    pub bench: fn(&str, &BenchConfig) -> Result<PartBench, Error>,
+}
+
+impl Solver {
+    const fn part1<S: Solution>(day: u32) -> Self {
+        Solver {
+            day,
+            part: 1,
+            run: run_part1::<S>,
+            bench: bench_part1::<S>,
+        }
+    }
+
+    const fn part2<S: Solution>(day: u32) -> Self {
+        Solver {
+            day,
+            part: 2,
+            run: run_part2::<S>,
+            bench: bench_part2::<S>,
+        }
+    }
+}
+
+// Day 24 part 2 was solved by hand and day 25 has no part 2,
+// so neither has a solver here.
+pub const SOLVERS: &[Solver] = &[
+    Solver::part1::<day01::Day01>(1),
+    Solver::part2::<day01::Day01>(1),
+    Solver::part1::<day02::Day02>(2),
+    Solver::part2::<day02::Day02>(2),
+    Solver::part1::<day03::Day03>(3),
+    Solver::part2::<day03::Day03>(3),
+    Solver::part1::<day04::Day04>(4),
+    Solver::part2::<day04::Day04>(4),
+    Solver::part1::<day05::Day05>(5),
+    Solver::part2::<day05::Day05>(5),
+    Solver::part1::<day06::Day06>(6),
+    Solver::part2::<day06::Day06>(6),
+    Solver::part1::<day07::Day07>(7),
+    Solver::part2::<day07::Day07>(7),
+    Solver::part1::<day08::Day08>(8),
+    Solver::part2::<day08::Day08>(8),
+    Solver::part1::<day09::Day09>(9),
+    Solver::part2::<day09::Day09>(9),
+    Solver::part1::<day10::Day10>(10),
+    Solver::part2::<day10::Day10>(10),
+    Solver::part1::<day11::Day11>(11),
+    Solver::part2::<day11::Day11>(11),
+    Solver::part1::<day12::Day12>(12),
+    Solver::part2::<day12::Day12>(12),
+    Solver::part1::<day13::Day13>(13),
+    Solver::part2::<day13::Day13>(13),
+    Solver::part1::<day14::Day14>(14),
+    Solver::part2::<day14::Day14>(14),
+    Solver::part1::<day15::Day15>(15),
+    Solver::part2::<day15::Day15>(15),
+    Solver::part1::<day16::Day16>(16),
+    Solver::part2::<day16::Day16>(16),
+    Solver::part1::<day17::Day17>(17),
+    Solver::part2::<day17::Day17>(17),
+    Solver::part1::<day18::Day18>(18),
+    Solver::part2::<day18::Day18>(18),
+    Solver::part1::<day19::Day19>(19),
+    Solver::part2::<day19::Day19>(19),
+    Solver::part1::<day20::Day20>(20),
+    Solver::part2::<day20::Day20>(20),
+    Solver::part1::<day21::Day21>(21),
+    Solver::part2::<day21::Day21>(21),
+    Solver::part1::<day22::Day22>(22),
+    Solver::part2::<day22::Day22>(22),
+    Solver::part1::<day23::Day23>(23),
+    Solver::part2::<day23::Day23>(23),
+    Solver::part1::<day24::Day24>(24),
+    Solver::part1::<day25::Day25>(25),
+];
+
+pub fn find_solver(day: u32, part: u32) -> Option<&'static Solver> {
+    SOLVERS.iter().find(|s| s.day == day && s.part == part)
+}
+
+pub fn solvers_for_day(day: u32) -> impl Iterator<Item = &'static Solver> {
+    SOLVERS.iter().filter(move |s| s.day == day)
+}
@@ -0,0 +1,243 @@
+use std::{fmt::Display, str::FromStr};
+
+/// An error encountered while parsing puzzle input.
+///
+/// Line and column numbers are 1-based.
+/// The offending text, if known, is the part of the input
+/// that could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub text: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            line: None,
+            column: None,
+            text: None,
+        }
+    }
+
+    pub fn invalid(message: impl Into<String>, text: &str) -> Self {
+        Self {
+            text: Some(text.to_owned()),
+            ..Self::new(message)
+        }
+    }
+
+    /// Sets the line number, unless one is already set.
+    ///
+    /// Errors are usually created deep inside a parser
+    /// and given their line number on the way out,
+    /// so the innermost (most accurate) line number wins.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Sets the column number, unless one is already set.
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    /// Sets the column to the position of `text` within `line`.
+    ///
+    /// `text` must be a subslice of `line`,
+    /// otherwise the column is left unset.
+    pub fn within(self, line: &str, text: &str) -> Self {
+        match column_of(line, text) {
+            Some(column) => self.at_column(column),
+            None => self,
+        }
+    }
+}
+
+fn column_of(line: &str, text: &str) -> Option<usize> {
+    let offset = (text.as_ptr() as usize).checked_sub(line.as_ptr() as usize)?;
+    if offset + text.len() > line.len() {
+        return None;
+    }
+    line.get(..offset).map(|prefix| prefix.chars().count() + 1)
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (Some(line), None) => write!(f, "line {}: ", line)?,
+            (None, Some(column)) => write!(f, "column {}: ", column)?,
+            (None, None) => {}
+        }
+        write!(f, "{}", self.message)?;
+        if let Some(text) = &self.text {
+            write!(f, ": {:?}", text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// An error encountered while solving an already parsed input,
+/// e.g. because the input has no solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// Any error that can come out of running a solver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    Solve(SolveError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse(e) => write!(f, "parse error: {}", e),
+            Error::Solve(e) => write!(f, "solve error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+impl From<SolveError> for Error {
+    fn from(e: SolveError) -> Self {
+        Error::Solve(e)
+    }
+}
+
+/// Parses `text` using its `FromStr` impl.
+pub fn parse_str<T>(text: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.parse()
+        .map_err(|e| ParseError::invalid(format!("invalid value ({})", e), text))
+}
+
+/// Parses `text`, which must be a subslice of `line`,
+/// recording its column within `line` on failure.
+pub fn parse_field<T>(line: &str, text: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    parse_str(text).map_err(|e| e.within(line, text))
+}
+
+/// Iterates over the lines of `s` along with their 1-based line numbers.
+pub fn numbered_lines(s: &str) -> impl Iterator<Item = (usize, &str)> {
+    s.lines().enumerate().map(|(i, l)| (i + 1, l))
+}
+
+/// Parses every line of `s` with `f`,
+/// recording the line number of the first line that fails.
+pub fn parse_lines<T, F>(s: &str, mut f: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    numbered_lines(s)
+        .map(|(n, l)| f(l).map_err(|e| e.at_line(n)))
+        .collect()
+}
+
+/// Parses every character of `line` with `f`,
+/// recording the column of the first character that is rejected.
+pub fn parse_chars<T, F>(line: &str, mut f: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(char) -> Option<T>,
+{
+    line.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            f(c).ok_or_else(|| {
+                ParseError::invalid("unexpected character", &c.to_string()).at_column(i + 1)
+            })
+        })
+        .collect()
+}
+
+/// Splits the lines of `s` into blank-line separated sections,
+/// keeping the 1-based line number of every line.
+pub fn sections(s: &str) -> Vec<Vec<(usize, &str)>> {
+    let lines = numbered_lines(s).collect::<Vec<_>>();
+    lines
+        .split(|(_, l)| l.is_empty())
+        .filter(|section| !section.is_empty())
+        .map(|section| section.to_vec())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_field_column() {
+        let line = "12 3x 45";
+        let err = parse_field::<i32>(line, &line[3..5]).unwrap_err();
+        assert_eq!(err.column, Some(4));
+        assert_eq!(err.text.as_deref(), Some("3x"));
+    }
+
+    #[test]
+    fn test_parse_lines_line_number() {
+        let err = parse_lines("1\n2\nx\n4\n", parse_str::<i32>).unwrap_err();
+        assert_eq!(err.line, Some(3));
+        assert_eq!(
+            err.to_string(),
+            "line 3: invalid value (invalid digit found in string): \"x\""
+        );
+    }
+
+    #[test]
+    fn test_parse_chars_column() {
+        let err = parse_chars("..#?", |c| matches!(c, '.' | '#').then_some(c)).unwrap_err();
+        assert_eq!(err.column, Some(4));
+        assert_eq!(err.text.as_deref(), Some("?"));
+    }
+
+    #[test]
+    fn test_at_line_keeps_innermost() {
+        let err = ParseError::new("oops").at_line(3).at_line(10);
+        assert_eq!(err.line, Some(3));
+    }
+
+    #[test]
+    fn test_sections() {
+        let s = sections("a\nb\n\nc\n");
+        assert_eq!(s, vec![vec![(1, "a"), (2, "b")], vec![(4, "c")]]);
+    }
+}
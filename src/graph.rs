@@ -0,0 +1,755 @@
+use std::{
+    borrow::Borrow,
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap, HashSet},
+    fmt::Display,
+    hash::Hash,
+    str::FromStr,
+};
+
+use crate::error::{parse_field, parse_lines, ParseError};
+
+/// A directed or undirected graph.
+///
+/// Nodes are interned to dense ids `0..len()` in the order they are added,
+/// and everything other than adding and looking up nodes works with ids,
+/// so algorithms can index vectors rather than hash node names.
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    directed: bool,
+    nodes: Vec<N>,
+    ids: HashMap<N, usize>,
+    successors: Vec<HashSet<usize>>,
+    predecessors: Vec<HashSet<usize>>,
+}
+
+impl<N: Hash + Eq + Clone> Graph<N> {
+    /// An empty graph whose edges go one way.
+    pub fn directed() -> Self {
+        Self::new(true)
+    }
+
+    /// An empty graph whose edges go both ways.
+    pub fn undirected() -> Self {
+        Self::new(false)
+    }
+
+    fn new(directed: bool) -> Self {
+        Self {
+            directed,
+            nodes: Vec::new(),
+            ids: HashMap::new(),
+            successors: Vec::new(),
+            predecessors: Vec::new(),
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// The number of nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Adds a node if it is not already present, returning its id.
+    pub fn add_node(&mut self, node: N) -> usize {
+        if let Some(&id) = self.ids.get(&node) {
+            return id;
+        }
+        let id = self.nodes.len();
+        self.nodes.push(node.clone());
+        self.ids.insert(node, id);
+        self.successors.push(HashSet::new());
+        self.predecessors.push(HashSet::new());
+        id
+    }
+
+    /// Adds an edge between two nodes, adding the nodes if needed,
+    /// and returns their ids.
+    pub fn add_edge(&mut self, from: N, to: N) -> (usize, usize) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.add_edge_ids(from, to);
+        (from, to)
+    }
+
+    pub fn add_edge_ids(&mut self, from: usize, to: usize) {
+        self.successors[from].insert(to);
+        self.predecessors[to].insert(from);
+        if !self.directed {
+            self.successors[to].insert(from);
+            self.predecessors[from].insert(to);
+        }
+    }
+
+    /// Looks up the id of a node.
+    pub fn id<Q>(&self, node: &Q) -> Option<usize>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.ids.get(node).copied()
+    }
+
+    pub fn node(&self, id: usize) -> &N {
+        &self.nodes[id]
+    }
+
+    /// Every node, indexed by id.
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    pub fn has_edge(&self, from: usize, to: usize) -> bool {
+        self.successors[from].contains(&to)
+    }
+
+    /// The nodes an edge from `id` leads to.
+    pub fn neighbours(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+        self.successors[id].iter().copied()
+    }
+
+    /// The nodes with an edge leading to `id`.
+    /// The same as the neighbours in an undirected graph.
+    pub fn predecessors(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+        self.predecessors[id].iter().copied()
+    }
+
+    /// The number of edges leaving `id`.
+    pub fn degree(&self, id: usize) -> usize {
+        self.successors[id].len()
+    }
+
+    /// The number of edges arriving at `id`.
+    pub fn in_degree(&self, id: usize) -> usize {
+        self.predecessors[id].len()
+    }
+
+    /// Every edge as a pair of ids.
+    /// Undirected edges are reported once, with the smaller id first.
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.successors.iter().enumerate().flat_map(move |(a, s)| {
+            s.iter()
+                .copied()
+                .filter(move |&b| self.directed || a <= b)
+                .map(move |b| (a, b))
+        })
+    }
+
+    /// The graph made of just the given nodes and the edges between them.
+    ///
+    /// Ids are not preserved: the nodes are renumbered in the order given.
+    pub fn induced_subgraph<I>(&self, ids: I) -> Graph<N>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let mut subgraph = Graph::new(self.directed);
+        let mut new_ids = HashMap::new();
+        for id in ids {
+            new_ids.insert(id, subgraph.add_node(self.nodes[id].clone()));
+        }
+        for (&old, &new) in &new_ids {
+            for to in self.neighbours(old) {
+                if let Some(&new_to) = new_ids.get(&to) {
+                    subgraph.add_edge_ids(new, new_to);
+                }
+            }
+        }
+        subgraph
+    }
+}
+
+impl<N: Hash + Eq + Clone> Graph<N> {
+    /// A comparator for `sort_by` that orders `nodes` by the edges between them,
+    /// read as rules where an edge from `a` to `b` means `a` comes before `b`.
+    ///
+    /// The nodes are ranked by a [`toposort`] of the subgraph they induce,
+    /// so the order is total even when the rules only partly order them.
+    /// Ties are broken by the order the nodes are given in,
+    /// and anything not in `nodes` sorts after them.
+    /// If the rules between `nodes` have a cycle, returns the nodes around it instead.
+    pub fn comparator(&self, nodes: &[N]) -> Result<impl Fn(&N, &N) -> Ordering, Vec<N>> {
+        let mut subgraph = self.induced_subgraph(nodes.iter().filter_map(|n| self.id(n)));
+        for n in nodes {
+            subgraph.add_node(n.clone());
+        }
+        let order = toposort(&subgraph).map_err(|cycle| {
+            cycle
+                .iter()
+                .map(|&id| subgraph.node(id).clone())
+                .collect::<Vec<_>>()
+        })?;
+        let ranks = order
+            .into_iter()
+            .enumerate()
+            .map(|(rank, id)| (subgraph.node(id).clone(), rank))
+            .collect::<HashMap<_, _>>();
+        Ok(move |a: &N, b: &N| {
+            let rank = |n: &N| ranks.get(n).copied().unwrap_or(usize::MAX);
+            rank(a).cmp(&rank(b))
+        })
+    }
+
+    /// Checks a sequence against the edges of a directed graph read as rules,
+    /// returning the first rule it breaks.
+    pub fn check_order(&self, seq: &[N]) -> Result<(), Violation<N>> {
+        let mut seen = HashMap::<usize, usize>::new();
+        for (i, node) in seq.iter().enumerate() {
+            let Some(id) = self.id(node) else {
+                continue;
+            };
+            let earliest_after = self
+                .neighbours(id)
+                .filter_map(|after| seen.get(&after).copied())
+                .min();
+            if let Some(after_index) = earliest_after {
+                return Err(Violation {
+                    before: node.clone(),
+                    after: seq[after_index].clone(),
+                    before_index: i,
+                    after_index,
+                });
+            }
+            seen.entry(id).or_insert(i);
+        }
+        Ok(())
+    }
+}
+
+/// A rule broken by a sequence:
+/// `before` should come before `after` but was found after it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation<N> {
+    pub before: N,
+    pub after: N,
+    /// Where `before` was found in the sequence.
+    pub before_index: usize,
+    /// Where `after` was found in the sequence.
+    pub after_index: usize,
+}
+
+impl<N: Display> Display for Violation<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (at {}) must come before {} (at {})",
+            self.before, self.before_index, self.after, self.after_index
+        )
+    }
+}
+
+impl<N: Hash + Eq + Clone> Extend<(N, N)> for Graph<N> {
+    fn extend<I: IntoIterator<Item = (N, N)>>(&mut self, edges: I) {
+        for (from, to) in edges {
+            self.add_edge(from, to);
+        }
+    }
+}
+
+impl<N> Graph<N>
+where
+    N: Hash + Eq + Clone + FromStr,
+    N::Err: Display,
+{
+    /// Adds one edge per line, written as two nodes around `separator`,
+    /// e.g. `kh-tc` with a separator of `-`.
+    pub fn parse_edges(mut self, s: &str, separator: &str) -> Result<Self, ParseError> {
+        let edges = parse_lines(s, |l| {
+            let (from, to) = l.split_once(separator).ok_or_else(|| {
+                ParseError::invalid(format!("expected two nodes separated by {}", separator), l)
+            })?;
+            Ok((parse_field(l, from)?, parse_field(l, to)?))
+        })?;
+        self.extend(edges);
+        Ok(self)
+    }
+
+    /// Adds one node per line followed by a colon
+    /// and the whitespace-separated nodes it has edges to, e.g. `a: b c d`.
+    pub fn parse_adjacency(mut self, s: &str) -> Result<Self, ParseError> {
+        let lines = parse_lines(s, |l| {
+            let (from, to) = l
+                .split_once(':')
+                .ok_or_else(|| ParseError::invalid("expected a line like a: b c", l))?;
+            let from: N = parse_field(l, from.trim())?;
+            let to = to
+                .split_whitespace()
+                .map(|n| parse_field(l, n))
+                .collect::<Result<Vec<N>, _>>()?;
+            Ok((from, to))
+        })?;
+        for (from, to) in lines {
+            let from = self.add_node(from);
+            for n in to {
+                let n = self.add_node(n);
+                self.add_edge_ids(from, n);
+            }
+        }
+        Ok(self)
+    }
+}
+
+/// Orders the nodes of a directed graph so that every edge points forwards,
+/// using Kahn's algorithm.
+///
+/// Ties are broken by id, so the result is deterministic.
+/// If the graph has a cycle, returns the ids around one of them instead.
+pub fn toposort<N>(graph: &Graph<N>) -> Result<Vec<usize>, Vec<usize>> {
+    let mut in_degrees = graph
+        .predecessors
+        .iter()
+        .map(|p| p.len())
+        .collect::<Vec<_>>();
+    let mut ready = in_degrees
+        .iter()
+        .enumerate()
+        .filter(|(_, &d)| d == 0)
+        .map(|(id, _)| Reverse(id))
+        .collect::<BinaryHeap<_>>();
+
+    let mut order = Vec::with_capacity(in_degrees.len());
+    while let Some(Reverse(id)) = ready.pop() {
+        order.push(id);
+        for &next in &graph.successors[id] {
+            in_degrees[next] -= 1;
+            if in_degrees[next] == 0 {
+                ready.push(Reverse(next));
+            }
+        }
+    }
+
+    if order.len() == in_degrees.len() {
+        Ok(order)
+    } else {
+        Err(find_cycle(graph, &in_degrees))
+    }
+}
+
+// Every node Kahn's algorithm could not order still has a predecessor that
+// could not be ordered either, so walking backwards through those must
+// eventually come round in a loop.
+fn find_cycle<N>(graph: &Graph<N>, in_degrees: &[usize]) -> Vec<usize> {
+    let stuck = |id: &usize| in_degrees[*id] > 0;
+    let mut id = (0..in_degrees.len()).find(stuck).unwrap();
+    let mut visited = HashMap::new();
+    let mut walk = Vec::new();
+    while !visited.contains_key(&id) {
+        visited.insert(id, walk.len());
+        walk.push(id);
+        id = *graph.predecessors[id].iter().find(|p| stuck(p)).unwrap();
+    }
+    let mut cycle = walk.split_off(visited[&id]);
+    // The walk went against the edges.
+    cycle.reverse();
+    cycle
+}
+
+/// Every maximal clique in an undirected graph, as lists of ids:
+/// sets of nodes that are all connected to each other
+/// and that cannot be extended by any other node.
+///
+/// Uses Bron–Kerbosch with pivoting.
+pub fn maximal_cliques<N>(graph: &Graph<N>) -> Vec<Vec<usize>> {
+    let mut cliques = Vec::new();
+    bron_kerbosch(
+        &graph.successors,
+        &mut Vec::new(),
+        (0..graph.successors.len()).collect(),
+        HashSet::new(),
+        &mut cliques,
+    );
+    cliques
+}
+
+/// The largest clique in an undirected graph,
+/// or an empty clique if the graph has no nodes.
+///
+/// If several cliques share the largest size, any one of them is returned.
+pub fn maximum_clique<N>(graph: &Graph<N>) -> Vec<usize> {
+    maximal_cliques(graph)
+        .into_iter()
+        .max_by_key(|c| c.len())
+        .unwrap_or_default()
+}
+
+// `clique` holds the nodes chosen so far, `candidates` the nodes that could
+// extend it, and `excluded` the nodes that could extend it but whose cliques
+// have all been reported already.
+fn bron_kerbosch(
+    neighbours: &[HashSet<usize>],
+    clique: &mut Vec<usize>,
+    mut candidates: HashSet<usize>,
+    mut excluded: HashSet<usize>,
+    cliques: &mut Vec<Vec<usize>>,
+) {
+    if candidates.is_empty() {
+        if excluded.is_empty() {
+            cliques.push(clique.clone());
+        }
+        return;
+    }
+
+    // Every maximal clique contains either the pivot or a non-neighbour of it,
+    // so only those need to be tried. Choosing the pivot with the most
+    // neighbours among the candidates leaves the fewest branches.
+    let pivot = *candidates
+        .iter()
+        .chain(excluded.iter())
+        .max_by_key(|&&u| neighbours[u].intersection(&candidates).count())
+        .unwrap();
+    let branches = candidates
+        .difference(&neighbours[pivot])
+        .copied()
+        .collect::<Vec<_>>();
+
+    for v in branches {
+        clique.push(v);
+        bron_kerbosch(
+            neighbours,
+            clique,
+            candidates.intersection(&neighbours[v]).copied().collect(),
+            excluded.intersection(&neighbours[v]).copied().collect(),
+            cliques,
+        );
+        clique.pop();
+        candidates.remove(&v);
+        excluded.insert(v);
+    }
+}
+
+/// Every clique of exactly `k` nodes in an undirected graph,
+/// each reported once as a list of ids in increasing order.
+pub fn k_cliques<N>(graph: &Graph<N>, k: usize) -> Vec<Vec<usize>> {
+    // Only ever extend a clique with higher ids,
+    // so that each clique is built in exactly one order.
+    let later_neighbours = graph
+        .successors
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            let mut later = s.iter().copied().filter(|&j| j > i).collect::<Vec<_>>();
+            later.sort();
+            later
+        })
+        .collect::<Vec<_>>();
+
+    let mut cliques = Vec::new();
+    if k > 0 {
+        extend_clique(
+            &later_neighbours,
+            &mut Vec::new(),
+            &(0..graph.successors.len()).collect::<Vec<_>>(),
+            k,
+            &mut cliques,
+        );
+    }
+    cliques
+}
+
+// Both `candidates` and the lists in `later_neighbours` are sorted,
+// so their intersections can be found with binary searches.
+fn extend_clique(
+    later_neighbours: &[Vec<usize>],
+    clique: &mut Vec<usize>,
+    candidates: &[usize],
+    k: usize,
+    cliques: &mut Vec<Vec<usize>>,
+) {
+    if clique.len() == k {
+        cliques.push(clique.clone());
+        return;
+    }
+    for &v in candidates {
+        let later = &later_neighbours[v];
+        let (small, large) = if later.len() < candidates.len() {
+            (later.as_slice(), candidates)
+        } else {
+            (candidates, later.as_slice())
+        };
+        let next = small
+            .iter()
+            .copied()
+            .filter(|c| large.binary_search(c).is_ok())
+            .collect::<Vec<_>>();
+
+        clique.push(v);
+        extend_clique(later_neighbours, clique, &next, k, cliques);
+        clique.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn undirected(edges: &[(&'static str, &'static str)]) -> Graph<&'static str> {
+        let mut graph = Graph::undirected();
+        graph.extend(edges.iter().copied());
+        graph
+    }
+
+    fn names(graph: &Graph<&'static str>, cliques: Vec<Vec<usize>>) -> Vec<Vec<&'static str>> {
+        let mut cliques = cliques
+            .into_iter()
+            .map(|c| {
+                let mut c = c.into_iter().map(|id| *graph.node(id)).collect::<Vec<_>>();
+                c.sort();
+                c
+            })
+            .collect::<Vec<_>>();
+        cliques.sort();
+        cliques
+    }
+
+    // A 4-clique a,b,c,d where every member also belongs to a triangle
+    // with two outside nodes. Growing a clique greedily from any member
+    // and its neighbours in the wrong order gets stuck on a triangle.
+    fn decoy_graph() -> Graph<&'static str> {
+        undirected(&[
+            ("a", "p"),
+            ("a", "q"),
+            ("p", "q"),
+            ("b", "r"),
+            ("b", "s"),
+            ("r", "s"),
+            ("c", "t"),
+            ("c", "u"),
+            ("t", "u"),
+            ("d", "v"),
+            ("d", "w"),
+            ("v", "w"),
+            ("a", "b"),
+            ("a", "c"),
+            ("a", "d"),
+            ("b", "c"),
+            ("b", "d"),
+            ("c", "d"),
+        ])
+    }
+
+    #[test]
+    fn test_interning() {
+        let mut graph = Graph::undirected();
+        assert_eq!(graph.add_edge("a", "b"), (0, 1));
+        assert_eq!(graph.add_edge("b", "c"), (1, 2));
+        assert_eq!(graph.add_node("a"), 0);
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.id("c"), Some(2));
+        assert_eq!(graph.id("d"), None);
+        assert_eq!(*graph.node(1), "b");
+    }
+
+    #[test]
+    fn test_directed() {
+        let mut graph = Graph::directed();
+        graph.extend([(1, 2), (1, 3), (3, 2)]);
+        let two = graph.id(&2).unwrap();
+        let one = graph.id(&1).unwrap();
+        assert!(graph.has_edge(one, two));
+        assert!(!graph.has_edge(two, one));
+        assert_eq!(graph.degree(one), 2);
+        assert_eq!(graph.degree(two), 0);
+        assert_eq!(graph.in_degree(two), 2);
+        assert_eq!(graph.edges().count(), 3);
+    }
+
+    #[test]
+    fn test_undirected() {
+        let graph = undirected(&[("a", "b"), ("b", "c")]);
+        let b = graph.id("b").unwrap();
+        let mut neighbours = graph
+            .neighbours(b)
+            .map(|n| *graph.node(n))
+            .collect::<Vec<_>>();
+        neighbours.sort();
+        assert_eq!(neighbours, vec!["a", "c"]);
+        assert_eq!(graph.degree(b), 2);
+        assert_eq!(graph.in_degree(b), 2);
+        assert_eq!(graph.edges().count(), 2);
+    }
+
+    #[test]
+    fn test_induced_subgraph() {
+        let graph = decoy_graph();
+        let ids = ["a", "b", "p", "r"].map(|n| graph.id(n).unwrap());
+        let subgraph = graph.induced_subgraph(ids);
+        assert_eq!(subgraph.nodes(), &["a", "b", "p", "r"]);
+        assert_eq!(subgraph.edges().count(), 3);
+        assert!(!subgraph.has_edge(2, 3));
+    }
+
+    #[test]
+    fn test_parse_edges() {
+        let graph = Graph::<String>::undirected()
+            .parse_edges("kh-tc\nqp-kh\n", "-")
+            .unwrap();
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.degree(graph.id("kh").unwrap()), 2);
+
+        let err = Graph::<u32>::directed()
+            .parse_edges("1|2\n3|x\n", "|")
+            .unwrap_err();
+        assert_eq!(err.line, Some(2));
+        assert_eq!(err.column, Some(3));
+    }
+
+    #[test]
+    fn test_parse_adjacency() {
+        let graph = Graph::<String>::directed()
+            .parse_adjacency("a: b c\nb: c\nd:\n")
+            .unwrap();
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph.edges().count(), 3);
+        assert_eq!(graph.in_degree(graph.id("c").unwrap()), 2);
+        assert_eq!(graph.degree(graph.id("d").unwrap()), 0);
+    }
+
+    #[test]
+    fn test_maximal_cliques() {
+        let graph = undirected(&[("a", "b"), ("b", "c"), ("a", "c"), ("c", "d")]);
+        assert_eq!(
+            names(&graph, maximal_cliques(&graph)),
+            vec![vec!["a", "b", "c"], vec!["c", "d"]]
+        );
+    }
+
+    #[test]
+    fn test_maximum_clique_with_decoys() {
+        let graph = decoy_graph();
+        assert_eq!(
+            names(&graph, vec![maximum_clique(&graph)]),
+            vec![vec!["a", "b", "c", "d"]]
+        );
+    }
+
+    #[test]
+    fn test_maximum_clique_where_first_neighbour_misleads() {
+        // Starting from x and adding its neighbours in order y, a, b, c
+        // finds only x,y; the real answer avoids y entirely.
+        let graph = undirected(&[
+            ("x", "y"),
+            ("x", "a"),
+            ("x", "b"),
+            ("x", "c"),
+            ("a", "b"),
+            ("a", "c"),
+            ("b", "c"),
+        ]);
+        assert_eq!(
+            names(&graph, vec![maximum_clique(&graph)]),
+            vec![vec!["a", "b", "c", "x"]]
+        );
+    }
+
+    #[test]
+    fn test_maximum_clique_empty() {
+        assert!(maximum_clique(&Graph::<u32>::undirected()).is_empty());
+    }
+
+    #[test]
+    fn test_k_cliques() {
+        let graph = decoy_graph();
+        // Four inside the 4-clique plus one decoy per member.
+        assert_eq!(k_cliques(&graph, 3).len(), 8);
+        assert_eq!(k_cliques(&graph, 4).len(), 1);
+        assert!(k_cliques(&graph, 5).is_empty());
+        assert_eq!(k_cliques(&graph, 1).len(), 12);
+    }
+
+    #[test]
+    fn test_toposort() {
+        let mut graph = Graph::directed();
+        graph.extend([("c", "a"), ("b", "a"), ("c", "b"), ("d", "e")]);
+        let order = toposort(&graph)
+            .unwrap()
+            .into_iter()
+            .map(|id| *graph.node(id))
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec!["c", "b", "a", "d", "e"]);
+    }
+
+    #[test]
+    fn test_toposort_cycle() {
+        let mut graph = Graph::directed();
+        graph.extend([("x", "a"), ("a", "b"), ("b", "c"), ("c", "a"), ("c", "y")]);
+        let mut cycle = toposort(&graph)
+            .unwrap_err()
+            .into_iter()
+            .map(|id| *graph.node(id))
+            .collect::<Vec<_>>();
+        // Every consecutive pair, wrapping round, is an edge.
+        for i in 0..cycle.len() {
+            let from = graph.id(cycle[i]).unwrap();
+            let to = graph.id(cycle[(i + 1) % cycle.len()]).unwrap();
+            assert!(graph.has_edge(from, to));
+        }
+        cycle.sort();
+        assert_eq!(cycle, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_comparator() {
+        let mut rules = Graph::directed();
+        rules.extend([(97, 75), (75, 47), (97, 47), (47, 61), (75, 61), (97, 61)]);
+        let mut pages = vec![61, 75, 97, 47];
+        let compare = rules.comparator(&pages).unwrap();
+        pages.sort_by(compare);
+        assert_eq!(pages, vec![97, 75, 47, 61]);
+    }
+
+    #[test]
+    fn test_comparator_partial_rules() {
+        // 1 only comes before 3 by way of 2, which is not being sorted,
+        // so only 3 before 4 constrains the order.
+        let mut rules = Graph::directed();
+        rules.extend([(3, 4), (1, 2), (2, 3)]);
+        let mut pages = vec![4, 3, 1];
+        let compare = rules.comparator(&pages).unwrap();
+        pages.sort_by(&compare);
+        assert_eq!(pages, vec![3, 4, 1]);
+        assert_eq!(rules.check_order(&pages), Ok(()));
+
+        // Nodes that were not given sort last.
+        assert_eq!(compare(&1, &2), Ordering::Less);
+        assert_eq!(compare(&2, &99), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_comparator_cycle() {
+        let mut rules = Graph::directed();
+        rules.extend([(1, 2), (2, 3), (3, 1), (3, 4)]);
+        let mut cycle = rules.comparator(&[4, 2, 3, 1]).err().unwrap();
+        cycle.sort();
+        assert_eq!(cycle, vec![1, 2, 3]);
+        assert!(rules.comparator(&[1, 2, 4]).is_ok());
+    }
+
+    #[test]
+    fn test_check_order() {
+        let mut rules = Graph::directed();
+        rules.extend([(97, 75), (75, 47), (47, 53)]);
+        assert_eq!(rules.check_order(&[97, 75, 47, 53]), Ok(()));
+        assert_eq!(rules.check_order(&[13, 97, 47]), Ok(()));
+
+        let violation = rules.check_order(&[75, 97, 47, 53]).unwrap_err();
+        assert_eq!(
+            violation,
+            Violation {
+                before: 97,
+                after: 75,
+                before_index: 1,
+                after_index: 0,
+            }
+        );
+        assert_eq!(
+            violation.to_string(),
+            "97 (at 1) must come before 75 (at 0)"
+        );
+    }
+}
@@ -1,6 +1,12 @@
+use std::{fmt::Display, str::FromStr};
+
 use crate::{
+    bit_grid::BitGrid,
     direction::{DIRECTIONS, EIGHT_WAY_DIRECTIONS},
+    error::{numbered_lines, parse_chars, ParseError},
     position::{pos, Position},
+    render::Render,
+    torus::WrappingGrid,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -9,18 +15,53 @@ pub struct Grid<T> {
     pub vec: Vec<T>,
 }
 
+/// An axis-aligned rectangle of cells, given by its top-left corner and size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Rect {
+    pub fn new(x: usize, y: usize, width: usize, height: usize) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
 impl Grid<char> {
+    /// Panics if the lines are not all the same length.
     pub fn from_strings(lines: &[String]) -> Self {
-        if lines.is_empty() {
-            return Grid {
-                width: 0,
-                vec: vec![],
-            };
-        }
+        let rows = lines
+            .iter()
+            .map(|l| l.chars().collect())
+            .collect::<Vec<_>>();
+        Self::from_vecs(&rows)
+    }
 
-        let width = lines[0].len();
-        let vec = lines.iter().flat_map(|l| l.chars()).collect();
-        Grid { width, vec }
+    /// Parses one cell per character, one row per line.
+    pub fn try_from_str(s: &str) -> Result<Self, ParseError> {
+        Self::parse_with(s, Some)
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.render(|&c| c).fmt(f)
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
     }
 }
 
@@ -32,27 +73,149 @@ impl<T: Clone> Grid<T> {
         }
     }
 
-    pub fn from_vecs(lines: &[Vec<T>]) -> Self {
-        if lines.is_empty() {
-            return Grid {
-                width: 0,
-                vec: vec![],
-            };
+    /// The rows may be given as a `Vec`, an array or a slice.
+    ///
+    /// Panics if the rows are not all the same length.
+    pub fn from_vecs<L>(lines: &L) -> Self
+    where
+        L: AsRef<[Vec<T>]> + ?Sized,
+    {
+        Self::try_from_vecs(lines).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Fails with the (1-based) number of the first row
+    /// whose length differs from the first row's.
+    pub fn try_from_vecs<L>(lines: &L) -> Result<Self, ParseError>
+    where
+        L: AsRef<[Vec<T>]> + ?Sized,
+    {
+        let lines = lines.as_ref();
+        let width = lines.first().map_or(0, |l| l.len());
+        if let Some((i, l)) = lines.iter().enumerate().find(|(_, l)| l.len() != width) {
+            return Err(ParseError::new(ragged_row(l.len(), width)).at_line(i + 1));
         }
-
-        let width = lines[0].len();
-        let vec = lines.concat();
-        Grid { width, vec }
+        Ok(Grid {
+            width,
+            vec: lines.concat(),
+        })
     }
 
     pub fn resize_height(&mut self, rows: usize, val: T) {
         self.vec.resize(self.width * rows, val);
     }
+
+    /// The grid turned a quarter turn clockwise, so the left column becomes the top row.
+    pub fn rotate_cw(&self) -> Self {
+        let height = self.height();
+        Grid::from_fn(height, self.width, |x, y| {
+            self.get(y, height - 1 - x).clone()
+        })
+    }
+
+    /// The grid turned a quarter turn anticlockwise, so the top row becomes the left column.
+    pub fn rotate_ccw(&self) -> Self {
+        let width = self.width;
+        Grid::from_fn(self.height(), width, |x, y| {
+            self.get(width - 1 - y, x).clone()
+        })
+    }
+
+    /// The grid mirrored left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let width = self.width;
+        Grid::from_fn(width, self.height(), |x, y| {
+            self.get(width - 1 - x, y).clone()
+        })
+    }
+
+    /// The grid mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let height = self.height();
+        Grid::from_fn(self.width, height, |x, y| {
+            self.get(x, height - 1 - y).clone()
+        })
+    }
+
+    /// The grid mirrored along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        Grid::from_fn(self.height(), self.width, |x, y| self.get(y, x).clone())
+    }
+
+    /// A copy of the cells within `rect`, which must lie inside the grid.
+    pub fn subgrid(&self, rect: Rect) -> Self {
+        assert!(
+            rect.x + rect.width <= self.width && rect.y + rect.height <= self.height(),
+            "{:?} is outside a {}x{} grid",
+            rect,
+            self.width,
+            self.height()
+        );
+        let vec = (rect.y..rect.y + rect.height)
+            .flat_map(|y| self.row(y)[rect.x..rect.x + rect.width].iter().cloned())
+            .collect();
+        Grid {
+            width: rect.width,
+            vec,
+        }
+    }
+
+    /// The grid repeated `nx` times across and `ny` times down.
+    pub fn tile(&self, nx: usize, ny: usize) -> Self {
+        let (width, height) = (self.width, self.height());
+        Grid::from_fn(width * nx, height * ny, |x, y| {
+            self.get(x % width, y % height).clone()
+        })
+    }
 }
 
 impl<T> Grid<T> {
+    /// Builds a grid by calling `f(x, y)` for each cell, row by row.
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let vec = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Grid { width, vec }
+    }
+
+    /// Parses one cell per character with `f`, one row per line,
+    /// rejecting unknown characters and rows of differing lengths.
+    pub fn parse_with<F>(s: &str, f: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let lines = numbered_lines(s).collect::<Vec<_>>();
+        Self::parse_section(&lines, f)
+    }
+
+    /// Like [`Grid::parse_with`], for numbered lines taken from a larger input
+    /// (see [`crate::error::sections`]).
+    pub fn parse_section<F>(lines: &[(usize, &str)], mut f: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut vec = Vec::new();
+        for &(n, l) in lines {
+            let row = parse_chars(l, &mut f).map_err(|e| e.at_line(n))?;
+            let expected = *width.get_or_insert(row.len());
+            if row.len() != expected {
+                return Err(ParseError::invalid(ragged_row(row.len(), expected), l).at_line(n));
+            }
+            vec.extend(row);
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            vec,
+        })
+    }
+
+    /// A grid with no columns has no rows either.
     pub fn height(&self) -> usize {
-        self.vec.len() / self.width
+        self.vec.len().checked_div(self.width).unwrap_or(0)
     }
 
     pub fn to_vec_index(&self, x: usize, y: usize) -> Option<usize> {
@@ -168,6 +331,59 @@ impl<T> Grid<T> {
     pub fn rows(&self) -> impl Iterator<Item = usize> {
         0..self.height()
     }
+
+    /// The cells of row `y`, left to right.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.vec[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.vec[y * self.width..(y + 1) * self.width]
+    }
+
+    /// The cells of column `x`, top to bottom.
+    pub fn col(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside the grid", x);
+        self.vec.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        // An empty grid may have no width; it has no rows either way.
+        self.vec.chunks(self.width.max(1))
+    }
+
+    /// A picture of the grid, drawing each cell as `glyph(cell)`.
+    pub fn render<F>(&self, glyph: F) -> Render<'_, T, F>
+    where
+        F: Fn(&T) -> char,
+    {
+        Render::new(self, glyph)
+    }
+
+    /// A bit grid of the cells for which `pred` holds.
+    pub fn map_to_bits<F>(&self, pred: F) -> BitGrid
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut bits = BitGrid::new(self.width, self.height());
+        for (p, v) in self.enumerate() {
+            if pred(v) {
+                bits.set_pos(&p, true);
+            }
+        }
+        bits
+    }
+
+    /// A view of the grid with its edges wrapped around.
+    ///
+    /// Panics if the grid has no cells.
+    pub fn wrapping(&self) -> WrappingGrid<'_, T> {
+        WrappingGrid::new(self)
+    }
+
+    pub fn iter_cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        self.cols().map(|x| self.col(x))
+    }
 }
 
 impl<T> IntoIterator for Grid<T> {
@@ -188,16 +404,15 @@ impl<'a, T> IntoIterator for &'a Grid<T> {
     }
 }
 
+fn ragged_row(len: usize, width: usize) -> String {
+    format!("row has {} cells but the first row has {}", len, width)
+}
+
 pub fn print_grid<T, F>(g: &Grid<T>, f: F)
 where
     F: Fn(&T) -> char,
 {
-    for r in g.rows() {
-        for c in g.cols() {
-            print!("{}", f(g.get(c, r)));
-        }
-        println!();
-    }
+    print!("{}", g.render(f));
 }
 
 #[cfg(test)]
@@ -401,4 +616,274 @@ mod tests {
             vec![(pos(1, 0), &'a'), (pos(1, 1), &'a'), (pos(0, 1), &'a')]
         );
     }
+
+    fn abc_def() -> Grid<char> {
+        Grid::from_vecs(&[vec!['a', 'b', 'c'], vec!['d', 'e', 'f']])
+    }
+
+    fn grid_of(rows: &[&str]) -> Grid<char> {
+        let lines = rows.iter().map(|r| r.to_string()).collect::<Vec<_>>();
+        Grid::from_strings(&lines)
+    }
+
+    #[test]
+    fn test_grid_from_fn() {
+        let g = Grid::from_fn(3, 2, |x, y| (x, y));
+        assert_eq!(g.width, 3);
+        assert_eq!(g.height(), 2);
+        assert_eq!(g.get(0, 0), &(0, 0));
+        assert_eq!(g.get(2, 0), &(2, 0));
+        assert_eq!(g.get(1, 1), &(1, 1));
+    }
+
+    #[test]
+    fn test_grid_rotate_cw() {
+        let g = abc_def().rotate_cw();
+        assert_eq!(g, grid_of(&["da", "eb", "fc"]));
+        assert_eq!(g.width, 2);
+        assert_eq!(g.height(), 3);
+    }
+
+    #[test]
+    fn test_grid_rotate_ccw() {
+        let g = abc_def().rotate_ccw();
+        assert_eq!(g, grid_of(&["cf", "be", "ad"]));
+        assert_eq!(g.width, 2);
+        assert_eq!(g.height(), 3);
+    }
+
+    #[test]
+    fn test_grid_rotations_compose() {
+        let g = abc_def();
+        assert_eq!(g.rotate_cw().rotate_ccw(), g);
+        assert_eq!(g.rotate_ccw().rotate_cw(), g);
+        assert_eq!(g.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), g);
+        assert_eq!(g.rotate_cw().rotate_cw(), g.rotate_ccw().rotate_ccw());
+        assert_eq!(
+            g.rotate_cw().rotate_cw(),
+            g.flip_horizontal().flip_vertical()
+        );
+        assert_eq!(g.rotate_cw(), g.transpose().flip_horizontal());
+        assert_eq!(g.rotate_ccw(), g.transpose().flip_vertical());
+    }
+
+    #[test]
+    fn test_grid_flip_horizontal() {
+        let g = abc_def();
+        assert_eq!(g.flip_horizontal(), grid_of(&["cba", "fed"]));
+        assert_eq!(g.flip_horizontal().flip_horizontal(), g);
+    }
+
+    #[test]
+    fn test_grid_flip_vertical() {
+        let g = abc_def();
+        assert_eq!(g.flip_vertical(), grid_of(&["def", "abc"]));
+        assert_eq!(g.flip_vertical().flip_vertical(), g);
+    }
+
+    #[test]
+    fn test_grid_transpose() {
+        let g = abc_def();
+        let t = g.transpose();
+        assert_eq!(t, grid_of(&["ad", "be", "cf"]));
+        assert_eq!(t.transpose(), g);
+        for (p, v) in g.enumerate() {
+            assert_eq!(t.get_pos(&pos(p.y, p.x)), v);
+        }
+    }
+
+    #[test]
+    fn test_grid_transforms_single_cell() {
+        let g = grid_of(&["x"]);
+        assert_eq!(g.rotate_cw(), g);
+        assert_eq!(g.rotate_ccw(), g);
+        assert_eq!(g.flip_horizontal(), g);
+        assert_eq!(g.flip_vertical(), g);
+        assert_eq!(g.transpose(), g);
+    }
+
+    #[test]
+    fn test_grid_subgrid() {
+        let g = grid_of(&["abcd", "efgh", "ijkl"]);
+        assert_eq!(g.subgrid(Rect::new(1, 1, 2, 2)), grid_of(&["fg", "jk"]));
+        assert_eq!(g.subgrid(Rect::new(0, 0, 4, 3)), g);
+        assert_eq!(g.subgrid(Rect::new(3, 0, 1, 3)), grid_of(&["d", "h", "l"]));
+        assert_eq!(g.subgrid(Rect::new(0, 2, 4, 1)), grid_of(&["ijkl"]));
+        assert_eq!(g.subgrid(Rect::new(2, 1, 0, 0)).vec, vec![]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_grid_subgrid_out_of_bounds() {
+        grid_of(&["abcd", "efgh", "ijkl"]).subgrid(Rect::new(2, 1, 3, 1));
+    }
+
+    #[test]
+    fn test_grid_tile() {
+        let g = grid_of(&["ab", "cd"]);
+        assert_eq!(
+            g.tile(3, 2),
+            grid_of(&["ababab", "cdcdcd", "ababab", "cdcdcd"])
+        );
+        assert_eq!(g.tile(1, 1), g);
+        assert_eq!(g.tile(2, 1), grid_of(&["abab", "cdcd"]));
+        assert_eq!(g.tile(1, 2), grid_of(&["ab", "cd", "ab", "cd"]));
+    }
+
+    #[test]
+    fn test_grid_row() {
+        let mut g = abc_def();
+        assert_eq!(g.row(0), &['a', 'b', 'c']);
+        assert_eq!(g.row(1), &['d', 'e', 'f']);
+
+        g.row_mut(1).reverse();
+        assert_eq!(g, grid_of(&["abc", "fed"]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_grid_row_out_of_bounds() {
+        abc_def().row(2);
+    }
+
+    #[test]
+    fn test_grid_col() {
+        let g = abc_def();
+        assert_eq!(g.col(0).collect::<String>(), "ad");
+        assert_eq!(g.col(1).collect::<String>(), "be");
+        assert_eq!(g.col(2).collect::<String>(), "cf");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_grid_col_out_of_bounds() {
+        let _ = abc_def().col(3);
+    }
+
+    #[test]
+    fn test_grid_iter_rows_cols() {
+        let g = abc_def();
+        let rows = g.iter_rows().collect::<Vec<_>>();
+        assert_eq!(rows, vec![&['a', 'b', 'c'][..], &['d', 'e', 'f'][..]]);
+
+        let cols = g
+            .iter_cols()
+            .map(|c| c.collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(cols, vec!["ad", "be", "cf"]);
+
+        // The rows of the transpose are the columns of the original.
+        let t = g.transpose();
+        let t_rows = t
+            .iter_rows()
+            .map(|r| r.iter().collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(t_rows, cols);
+
+        let empty = Grid::<char>::from_vecs(&[]);
+        assert_eq!(empty.iter_rows().count(), 0);
+    }
+
+    #[test]
+    fn test_grid_try_from_str() {
+        let g = Grid::try_from_str("abc\ndef\n").unwrap();
+        assert_eq!(g, abc_def());
+
+        let g2: Grid<char> = "abc\ndef".parse().unwrap();
+        assert_eq!(g2, abc_def());
+    }
+
+    #[test]
+    fn test_grid_try_from_str_ragged() {
+        let err = Grid::try_from_str("abc\ndef\ngh\nijk\n").unwrap_err();
+        assert_eq!(err.line, Some(3));
+        assert_eq!(
+            err.to_string(),
+            "line 3: row has 2 cells but the first row has 3: \"gh\""
+        );
+
+        let err2 = Grid::try_from_str("abc\ndefg\n").unwrap_err();
+        assert_eq!(err2.line, Some(2));
+    }
+
+    #[test]
+    fn test_grid_try_from_str_non_ascii() {
+        // Widths are counted in characters, not bytes.
+        let g = Grid::try_from_str("é.\n.é\n").unwrap();
+        assert_eq!(g.width, 2);
+        assert_eq!(g.height(), 2);
+        assert_eq!(g.get(0, 0), &'é');
+        assert_eq!(g.get(1, 1), &'é');
+
+        let g2 = Grid::from_strings(&["é.".to_string(), ".é".to_string()]);
+        assert_eq!(g2, g);
+    }
+
+    #[test]
+    fn test_grid_try_from_str_empty() {
+        let g = Grid::try_from_str("").unwrap();
+        assert_eq!(g.width, 0);
+        assert_eq!(g.height(), 0);
+        assert_eq!(g.pos_iter().count(), 0);
+    }
+
+    #[test]
+    fn test_grid_try_from_vecs() {
+        let g = Grid::try_from_vecs(&[vec![1, 2], vec![3, 4]]).unwrap();
+        assert_eq!(g.width, 2);
+        assert_eq!(g.get(1, 1), &4);
+
+        let err = Grid::try_from_vecs(&[vec![1, 2], vec![3, 4], vec![5]]).unwrap_err();
+        assert_eq!(err.line, Some(3));
+        assert_eq!(
+            err.to_string(),
+            "line 3: row has 1 cells but the first row has 2"
+        );
+
+        let empty = Grid::<i32>::try_from_vecs(&[]).unwrap();
+        assert_eq!(empty.height(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "row has 1 cells but the first row has 2")]
+    fn test_grid_from_vecs_ragged() {
+        Grid::from_vecs(&[vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn test_grid_zero_width_height() {
+        let g = Grid::<char>::from_vecs(&[vec![], vec![]]);
+        assert_eq!(g.width, 0);
+        assert_eq!(g.height(), 0);
+        assert_eq!(g.try_get(0, 0), None);
+    }
+
+    #[test]
+    fn test_grid_parse_with() {
+        let g = Grid::parse_with("1.2\n3#4\n", |c| match c {
+            '.' | '#' => Some(0),
+            _ => c.to_digit(10),
+        })
+        .unwrap();
+        assert_eq!(g, Grid::from_vecs(&[vec![1, 0, 2], vec![3, 0, 4]]));
+
+        let err = Grid::parse_with("12\n3x\n", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(2)));
+        assert_eq!(err.text.as_deref(), Some("x"));
+    }
+
+    #[test]
+    fn test_grid_parse_section() {
+        let lines = [(5, "ab"), (6, "c")];
+        let err = Grid::parse_section(&lines, Some).unwrap_err();
+        assert_eq!(err.line, Some(6));
+    }
+
+    #[test]
+    fn test_grid_display() {
+        let text = "#..\n.#.\n..#\n";
+        let g = Grid::try_from_str(text).unwrap();
+        assert_eq!(g.to_string(), text);
+        assert_eq!(g.to_string().parse::<Grid<char>>().unwrap(), g);
+    }
 }
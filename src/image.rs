@@ -0,0 +1,358 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::grid::Grid;
+
+/// A colour as red, green and blue intensities.
+pub type Rgb = [u8; 3];
+
+/// A picture made of one colour per pixel, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// One pixel per cell, coloured by `colour(cell)`.
+    pub fn from_grid<T, F>(grid: &Grid<T>, colour: F) -> Self
+    where
+        F: Fn(&T) -> Rgb,
+    {
+        Self {
+            width: grid.width,
+            height: grid.height(),
+            pixels: grid.iter().map(colour).collect(),
+        }
+    }
+
+    /// The image with every pixel blown up into a `factor` by `factor` square,
+    /// since one pixel per cell is too small to see for most puzzle grids.
+    pub fn scaled(&self, factor: usize) -> Self {
+        let width = self.width * factor;
+        let height = self.height * factor;
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x / factor, y / factor)))
+            .map(|(x, y)| self.pixels[y * self.width + x])
+            .collect();
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Writes the image as a binary PPM (P6), which most image viewers can open.
+    pub fn write_ppm<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        write!(w, "P6\n{} {}\n255\n", self.width, self.height)?;
+        w.write_all(&self.pixels.concat())
+    }
+
+    /// Writes the image as an 8-bit RGB PNG.
+    ///
+    /// The pixel data is stored without compression,
+    /// which keeps the encoder short at the cost of larger files.
+    pub fn write_png<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        if self.width == 0 || self.height == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "a PNG must have at least one pixel",
+            ));
+        }
+
+        let mut header = Vec::with_capacity(13);
+        header.extend(png_dimension(self.width)?);
+        header.extend(png_dimension(self.height)?);
+        // Bit depth 8, colour type 2 (RGB), then the default compression,
+        // filter and interlace methods.
+        header.extend([8, 2, 0, 0, 0]);
+
+        // Each row starts with its filter type, which is always "none".
+        let mut raw = Vec::with_capacity(self.height * (1 + 3 * self.width));
+        for row in self.pixels.chunks(self.width) {
+            raw.push(0);
+            raw.extend(row.concat());
+        }
+
+        w.write_all(&PNG_SIGNATURE)?;
+        write_png_chunk(w, b"IHDR", &header)?;
+        write_png_chunk(w, b"IDAT", &zlib_stored(&raw))?;
+        write_png_chunk(w, b"IEND", &[])
+    }
+
+    /// Writes the image to `path`, as a PNG or PPM according to its extension.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut bytes = Vec::new();
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("png") => self.write_png(&mut bytes)?,
+            Some("ppm") => self.write_ppm(&mut bytes)?,
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{} is neither a .png nor a .ppm file", path.display()),
+                ))
+            }
+        }
+        fs::write(path, bytes)
+    }
+}
+
+/// Saves each frame as a numbered PNG in `dir`, e.g. `frame0000.png`,
+/// creating `dir` if needed, and returns the paths written.
+///
+/// The numbers are zero-padded so that the frames sort in order,
+/// ready to be stepped through or turned into a video.
+pub fn write_frames<I>(dir: &Path, prefix: &str, frames: I) -> io::Result<Vec<PathBuf>>
+where
+    I: IntoIterator<Item = Image>,
+{
+    fs::create_dir_all(dir)?;
+    frames
+        .into_iter()
+        .enumerate()
+        .map(|(i, frame)| {
+            let path = dir.join(format!("{}{:04}.png", prefix, i));
+            frame.save(&path)?;
+            Ok(path)
+        })
+        .collect()
+}
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+fn png_dimension(n: usize) -> io::Result<[u8; 4]> {
+    u32::try_from(n)
+        .map(u32::to_be_bytes)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "image too large for a PNG"))
+}
+
+fn write_png_chunk<W: Write>(w: &mut W, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    w.write_all(&png_dimension(data.len())?)?;
+    w.write_all(kind)?;
+    w.write_all(data)?;
+    let crc = crc32(kind.iter().chain(data));
+    w.write_all(&crc.to_be_bytes())
+}
+
+/// Wraps `data` in a zlib stream of uncompressed ("stored") deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = u16::MAX as usize;
+
+    // Deflate with a 32K window and no preset dictionary;
+    // the second byte makes the header a multiple of 31.
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(MAX_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let is_final = blocks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(u8::from(is_final));
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    let (a, b) = data.iter().fold((1, 0), |(a, b), &byte| {
+        let a = (a + u32::from(byte)) % MOD;
+        (a, (b + a) % MOD)
+    });
+    (b << 16) | a
+}
+
+fn crc32<'a, I>(data: I) -> u32
+where
+    I: IntoIterator<Item = &'a u8>,
+{
+    let table: Vec<u32> = (0..256)
+        .map(|n| {
+            (0..8).fold(n, |c, _| {
+                if c & 1 == 1 {
+                    0xedb8_8320 ^ (c >> 1)
+                } else {
+                    c >> 1
+                }
+            })
+        })
+        .collect();
+    !data.into_iter().fold(!0, |crc, &byte| {
+        table[((crc ^ u32::from(byte)) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLACK: Rgb = [0, 0, 0];
+    const WHITE: Rgb = [255, 255, 255];
+
+    fn checkerboard() -> Image {
+        let grid = Grid::from_vecs(&[vec![true, false, true], vec![false, true, false]]);
+        Image::from_grid(&grid, |&on| if on { WHITE } else { BLACK })
+    }
+
+    /// Reads back the pixel rows of a PNG written by `write_png`,
+    /// checking every chunk's CRC and the zlib checksum on the way.
+    fn decode_stored_png(bytes: &[u8]) -> (u32, u32, Vec<u8>) {
+        assert_eq!(bytes[..8], PNG_SIGNATURE);
+        let mut rest = &bytes[8..];
+        let mut chunks = Vec::new();
+        while !rest.is_empty() {
+            let len = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+            let (kind, data) = (&rest[4..8], &rest[8..8 + len]);
+            let crc = u32::from_be_bytes(rest[8 + len..12 + len].try_into().unwrap());
+            assert_eq!(crc, crc32(kind.iter().chain(data)));
+            chunks.push((kind, data));
+            rest = &rest[12 + len..];
+        }
+
+        let kinds = chunks.iter().map(|(k, _)| *k).collect::<Vec<_>>();
+        assert_eq!(kinds, vec![b"IHDR", b"IDAT", b"IEND"]);
+        let header = chunks[0].1;
+        let width = u32::from_be_bytes(header[..4].try_into().unwrap());
+        let height = u32::from_be_bytes(header[4..8].try_into().unwrap());
+        assert_eq!(header[8..], [8, 2, 0, 0, 0]);
+
+        let zlib = chunks[1].1;
+        assert_eq!(zlib[..2], [0x78, 0x01]);
+        let mut deflate = &zlib[2..zlib.len() - 4];
+        let mut raw = Vec::new();
+        loop {
+            let is_final = deflate[0] == 1;
+            let len = u16::from_le_bytes([deflate[1], deflate[2]]);
+            let nlen = u16::from_le_bytes([deflate[3], deflate[4]]);
+            assert_eq!(nlen, !len);
+            raw.extend(&deflate[5..5 + len as usize]);
+            deflate = &deflate[5 + len as usize..];
+            if is_final {
+                break;
+            }
+        }
+        assert!(deflate.is_empty());
+        let adler = u32::from_be_bytes(zlib[zlib.len() - 4..].try_into().unwrap());
+        assert_eq!(adler, adler32(&raw));
+
+        (width, height, raw)
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+        assert_eq!(adler32(b""), 1);
+    }
+
+    #[test]
+    fn test_from_grid() {
+        let image = checkerboard();
+        assert_eq!((image.width, image.height), (3, 2));
+        assert_eq!(image.pixels, vec![WHITE, BLACK, WHITE, BLACK, WHITE, BLACK]);
+    }
+
+    #[test]
+    fn test_scaled() {
+        let image = checkerboard().scaled(2);
+        assert_eq!((image.width, image.height), (6, 4));
+        assert_eq!(
+            image.pixels[..6],
+            [WHITE, WHITE, BLACK, BLACK, WHITE, WHITE]
+        );
+        assert_eq!(image.pixels[6..12], image.pixels[..6]);
+        assert_eq!(
+            image.pixels[12..18],
+            [BLACK, BLACK, WHITE, WHITE, BLACK, BLACK]
+        );
+    }
+
+    #[test]
+    fn test_write_ppm() {
+        let mut bytes = Vec::new();
+        checkerboard().write_ppm(&mut bytes).unwrap();
+
+        let header = b"P6\n3 2\n255\n";
+        assert_eq!(bytes[..header.len()], header[..]);
+        assert_eq!(bytes.len(), header.len() + 3 * 6);
+        assert_eq!(
+            bytes[header.len()..header.len() + 6],
+            [255, 255, 255, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_write_png() {
+        let mut bytes = Vec::new();
+        checkerboard().write_png(&mut bytes).unwrap();
+
+        let (width, height, raw) = decode_stored_png(&bytes);
+        assert_eq!((width, height), (3, 2));
+        assert_eq!(
+            raw,
+            [
+                [0].as_slice(),
+                &[255, 255, 255, 0, 0, 0, 255, 255, 255],
+                &[0],
+                &[0, 0, 0, 255, 255, 255, 0, 0, 0],
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn test_write_png_many_blocks() {
+        // More than 64K of pixel data needs several stored blocks.
+        let grid = Grid::from_fn(200, 150, |x, y| [x as u8, y as u8, 7]);
+        let image = Image::from_grid(&grid, |&c| c);
+        let mut bytes = Vec::new();
+        image.write_png(&mut bytes).unwrap();
+
+        let (width, height, raw) = decode_stored_png(&bytes);
+        assert_eq!((width, height), (200, 150));
+        assert_eq!(raw.len(), 150 * (1 + 3 * 200));
+        let row = 1 + 3 * 200;
+        assert_eq!(raw[row * 149..row * 149 + 4], [0, 0, 149, 7]);
+    }
+
+    #[test]
+    fn test_write_png_empty() {
+        let image = Image::from_grid(&Grid::<bool>::from_vecs(&[]), |_| BLACK);
+        assert!(image.write_png(&mut Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_write_frames() {
+        let dir = std::env::temp_dir().join(format!("aoc24-frames-{}", std::process::id()));
+        let frames = (0..3).map(|i| {
+            let grid = Grid::from_fn(3, 1, |x, _| x == i);
+            Image::from_grid(&grid, |&on| if on { WHITE } else { BLACK })
+        });
+
+        let paths = write_frames(&dir, "step", frames);
+        let second = fs::read(dir.join("step0001.png"));
+        let unknown = checkerboard().save(&dir.join("picture.jpg"));
+        fs::remove_dir_all(&dir).unwrap();
+
+        let names = paths
+            .unwrap()
+            .iter()
+            .map(|p| p.file_name().unwrap().to_str().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["step0000.png", "step0001.png", "step0002.png"]);
+
+        let (_, _, raw) = decode_stored_png(&second.unwrap());
+        assert_eq!(raw, [0, 0, 0, 0, 255, 255, 255, 0, 0, 0]);
+
+        assert_eq!(unknown.unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    }
+}
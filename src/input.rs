@@ -0,0 +1,92 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::answers::is_answers_file;
+
+/// The name of a day's own puzzle input within its data directory.
+pub const DEFAULT_INPUT: &str = "input";
+
+/// The path that means "read from stdin" wherever an input path is accepted.
+pub const STDIN_PATH: &str = "-";
+
+/// The `data` directory at the root of this repository.
+///
+/// This is fixed at compile time so that the runner
+/// works no matter which directory it is started from.
+pub fn default_data_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("data")
+}
+
+/// The directory holding all the inputs for a single day, e.g. `data/day17`.
+pub fn day_dir(data_dir: &Path, day: u32) -> PathBuf {
+    data_dir.join(format!("day{:02}", day))
+}
+
+/// The path of a named input for a day, e.g. `data/day17/example1`.
+pub fn input_path(data_dir: &Path, day: u32, name: &str) -> PathBuf {
+    day_dir(data_dir, day).join(name)
+}
+
+/// Lists the names of every input in a day's data directory,
+/// skipping any answers files stored alongside them.
+///
+/// The day's own puzzle input comes first
+/// and the rest (examples, other accounts' inputs) follow in name order.
+pub fn input_names(data_dir: &Path, day: u32) -> io::Result<Vec<String>> {
+    let mut names = fs::read_dir(day_dir(data_dir, day))?
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            entry.file_type().ok()?.is_file().then_some(())?;
+            entry.file_name().into_string().ok()
+        })
+        .filter(|name| !is_answers_file(name))
+        .collect::<Vec<_>>();
+    names.sort_by_key(|name| (name != DEFAULT_INPUT, name.clone()));
+    Ok(names)
+}
+
+/// Reads an input from `path`, or from stdin if `path` is [`STDIN_PATH`].
+pub fn read_input(path: &Path) -> io::Result<String> {
+    if path == Path::new(STDIN_PATH) {
+        let mut s = String::new();
+        io::stdin().read_to_string(&mut s)?;
+        Ok(s)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_path() {
+        let path = input_path(Path::new("data"), 7, "example1");
+        assert_eq!(path, Path::new("data/day07/example1"));
+    }
+
+    #[test]
+    fn test_input_names_puts_default_first() {
+        let dir = std::env::temp_dir().join(format!("aoc24-input-names-{}", std::process::id()));
+        let day = day_dir(&dir, 17);
+        fs::create_dir_all(&day).unwrap();
+        for name in [
+            "example2",
+            "input",
+            "answers",
+            "example1",
+            "example1.answers",
+        ] {
+            fs::write(day.join(name), "").unwrap();
+        }
+
+        let names = input_names(&dir, 17);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(names.unwrap(), vec!["input", "example1", "example2"]);
+    }
+}
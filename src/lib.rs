@@ -1,4 +1,18 @@
 pub mod algorithms;
+pub mod answers;
+pub mod bench;
+pub mod bit_grid;
+pub mod days;
 pub mod direction;
+pub mod error;
+pub mod graph;
 pub mod grid;
+pub mod image;
+pub mod input;
+pub mod math;
+pub mod memo;
 pub mod position;
+pub mod render;
+pub mod solution;
+pub mod sparse_grid;
+pub mod torus;
@@ -0,0 +1,417 @@
+/// The non-negative greatest common divisor of `a` and `b`.
+pub fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The non-negative least common multiple of `a` and `b`, or 0 if either is 0.
+pub fn lcm(a: i64, b: i64) -> i64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    (a / gcd(a, b) * b).abs()
+}
+
+/// Returns `(g, x, y)` where `g` is the non-negative greatest common divisor
+/// of `a` and `b`, and `a * x + b * y == g`.
+pub fn egcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The inverse of `a` modulo `m`, in `0..m`,
+/// or `None` if `m` is not positive or `a` and `m` are not coprime.
+pub fn mod_inv(a: i64, m: i64) -> Option<i64> {
+    if m <= 0 {
+        return None;
+    }
+    let (g, x, _) = egcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// `base` to the power `exp` modulo `m`, in `0..m`,
+/// or `None` if `m` is not positive.
+pub fn mod_pow(base: i64, mut exp: u64, m: i64) -> Option<i64> {
+    if m <= 0 {
+        return None;
+    }
+    let m = m as i128;
+    let mut base = (base as i128).rem_euclid(m);
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    Some(result as i64)
+}
+
+/// Finds `x` with `x ≡ a (mod m)` for every `(a, m)` given,
+/// returning `(x, l)` where `l` is the lcm of the moduli and `x` is in `0..l`.
+/// Every solution is then `x` plus some multiple of `l`.
+///
+/// The moduli need not be coprime; `None` means the congruences contradict each other,
+/// a modulus is not positive, or the lcm of the moduli does not fit in an `i64`.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let mut x: i128 = 0;
+    let mut l: i128 = 1;
+    for &(a, m) in congruences {
+        if m <= 0 {
+            return None;
+        }
+        let (a, m) = (a as i128, m as i128);
+        let (g, p, _) = egcd(l as i64, m as i64);
+        let g = g as i128;
+        let diff = a - x;
+        if diff % g != 0 {
+            return None;
+        }
+        // x + l * k ≡ a (mod m), so k ≡ (diff / g) * (l / g)^-1 (mod m / g),
+        // and the inverse is the coefficient of l from egcd.
+        let m_g = m / g;
+        let k = ((diff / g) % m_g * p as i128).rem_euclid(m_g);
+        x += l * k;
+        l *= m_g;
+        // l only grows, so once it is too big the answer will be too.
+        if l > i64::MAX as i128 {
+            return None;
+        }
+        x = x.rem_euclid(l);
+    }
+    Some((x as i64, l as i64))
+}
+
+/// Divides, rounding towards negative infinity.
+pub fn div_floor(a: i64, b: i64) -> i64 {
+    let q = a / b;
+    if a % b != 0 && (a < 0) != (b < 0) {
+        q - 1
+    } else {
+        q
+    }
+}
+
+/// Divides, rounding towards positive infinity.
+pub fn div_ceil(a: i64, b: i64) -> i64 {
+    let q = a / b;
+    if a % b != 0 && (a < 0) == (b < 0) {
+        q + 1
+    } else {
+        q
+    }
+}
+
+/// The integer solutions `(x, y)` of a system of two linear equations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinearSolutions {
+    None,
+    Unique(i64, i64),
+    /// Every `(x + t * dx, y + t * dy)` for integer `t`.
+    Line {
+        point: (i64, i64),
+        step: (i64, i64),
+    },
+    /// Every pair of integers, when both equations are `0 = 0`.
+    All,
+}
+
+/// Solves `m[0][0] * x + m[0][1] * y == c[0]` and `m[1][0] * x + m[1][1] * y == c[1]`
+/// over the integers.
+///
+/// When the determinant is zero the equations are either inconsistent
+/// or the same line, whose integer points are found with [`egcd`].
+pub fn solve_linear_2x2(m: [[i64; 2]; 2], c: [i64; 2]) -> LinearSolutions {
+    let [[a1, b1], [a2, b2]] = m;
+    let [c1, c2] = c;
+
+    // Cramer's rule.
+    let det = a1 * b2 - b1 * a2;
+    if det != 0 {
+        let x_num = c1 * b2 - b1 * c2;
+        let y_num = a1 * c2 - c1 * a2;
+        if x_num % det != 0 || y_num % det != 0 {
+            return LinearSolutions::None;
+        }
+        return LinearSolutions::Unique(x_num / det, y_num / det);
+    }
+
+    // The rows are parallel, so the system is just whichever one is not 0 = c,
+    // provided the other is a multiple of it.
+    let ((a, b, c), (other_a, other_b, other_c)) = if (a1, b1) != (0, 0) {
+        ((a1, b1, c1), (a2, b2, c2))
+    } else {
+        ((a2, b2, c2), (a1, b1, c1))
+    };
+    if (a, b) == (0, 0) {
+        return if c == 0 && other_c == 0 {
+            LinearSolutions::All
+        } else {
+            LinearSolutions::None
+        };
+    }
+    if a * other_c != other_a * c || b * other_c != other_b * c {
+        return LinearSolutions::None;
+    }
+
+    let (g, x, y) = egcd(a, b);
+    if c % g != 0 {
+        return LinearSolutions::None;
+    }
+    LinearSolutions::Line {
+        point: (x * (c / g), y * (c / g)),
+        step: (b / g, -a / g),
+    }
+}
+
+impl LinearSolutions {
+    /// The solution with `x >= 0` and `y >= 0` that minimises
+    /// `cost_x * x + cost_y * y`, or `None` if there is no such solution
+    /// or the cost can be made arbitrarily small.
+    ///
+    /// Where several solutions share the lowest cost, the one with the smallest `x` is returned.
+    pub fn cheapest_non_negative(&self, cost_x: i64, cost_y: i64) -> Option<(i64, i64)> {
+        match *self {
+            LinearSolutions::None => None,
+            LinearSolutions::Unique(x, y) => (x >= 0 && y >= 0).then_some((x, y)),
+            LinearSolutions::All => (cost_x >= 0 && cost_y >= 0).then_some((0, 0)),
+            LinearSolutions::Line {
+                point: (x, y),
+                step: (dx, dy),
+            } => {
+                let (lo_x, hi_x) = non_negative_range(x, dx)?;
+                let (lo_y, hi_y) = non_negative_range(y, dy)?;
+                let lo = max_bound(lo_x, lo_y);
+                let hi = min_bound(hi_x, hi_y);
+                if let (Some(lo), Some(hi)) = (lo, hi) {
+                    if lo > hi {
+                        return None;
+                    }
+                }
+
+                // The cost changes by the same amount with every step along the line,
+                // so the cheapest point is at one end of the range.
+                let slope = cost_x * dx + cost_y * dy;
+                // With dx == 0 every point has the same x, so either end will do.
+                let smallest_x_end = match dx {
+                    0 => lo.or(hi),
+                    d if d > 0 => lo,
+                    _ => hi,
+                };
+                let t = match slope {
+                    0 => smallest_x_end?,
+                    s if s > 0 => lo?,
+                    _ => hi?,
+                };
+                Some((x + t * dx, y + t * dy))
+            }
+        }
+    }
+}
+
+// The range of t for which `start + t * step >= 0`, with `None` for no bound.
+// Returns `None` overall if there is no such t.
+fn non_negative_range(start: i64, step: i64) -> Option<(Option<i64>, Option<i64>)> {
+    match step {
+        0 => (start >= 0).then_some((None, None)),
+        s if s > 0 => Some((Some(div_ceil(-start, s)), None)),
+        s => Some((None, Some(div_floor(-start, s)))),
+    }
+}
+
+fn max_bound(a: Option<i64>, b: Option<i64>) -> Option<i64> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+fn min_bound(a: Option<i64>, b: Option<i64>) -> Option<i64> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_egcd() {
+        for (a, b) in [(240, 46), (46, 240), (-240, 46), (7, 0), (0, -7), (17, 5)] {
+            let (g, x, y) = egcd(a, b);
+            assert!(g >= 0);
+            assert_eq!(a * x + b * y, g);
+        }
+        assert_eq!(egcd(240, 46).0, 2);
+        assert_eq!(egcd(0, -7).0, 7);
+    }
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(240, 46), 2);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(-4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(lcm(101, 103), 10403);
+    }
+
+    #[test]
+    fn test_mod_inv() {
+        assert_eq!(mod_inv(3, 11), Some(4));
+        assert_eq!(mod_inv(-3, 11), Some(7));
+        assert_eq!(mod_inv(101, 103), Some(51));
+        assert_eq!(mod_inv(6, 9), None);
+        assert_eq!(mod_inv(3, 0), None);
+        assert_eq!(mod_inv(3, -11), None);
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(mod_pow(2, 10, 1000), Some(24));
+        assert_eq!(mod_pow(-2, 3, 7), Some(6));
+        assert_eq!(mod_pow(5, 0, 7), Some(1));
+        assert_eq!(mod_pow(5, 0, 1), Some(0));
+        assert_eq!(mod_pow(5, 3, 0), None);
+        assert_eq!(mod_pow(5, 3, -7), None);
+        // Fermat: a^(p-1) = 1 mod p, with p near the top of i64.
+        let p = 9_223_372_036_854_775_783;
+        assert_eq!(mod_pow(123_456_789, (p - 1) as u64, p), Some(1));
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(46, 101), (53, 103)]).unwrap().0 % 101, 46);
+        assert_eq!(crt(&[(46, 101), (53, 103)]).unwrap().0 % 103, 53);
+        assert_eq!(crt(&[]), Some((0, 1)));
+    }
+
+    #[test]
+    fn test_crt_not_coprime() {
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(-1, 4), (3, 8)]), Some((3, 8)));
+    }
+
+    #[test]
+    fn test_crt_bad_moduli() {
+        assert_eq!(crt(&[(2, 3), (1, 0)]), None);
+        assert_eq!(crt(&[(2, -3)]), None);
+    }
+
+    #[test]
+    fn test_crt_overflow() {
+        let p = 9_223_372_036_854_775_783;
+        assert_eq!(crt(&[(p - 1, p)]), Some((p - 1, p)));
+        assert_eq!(crt(&[(1, p), (1, 2)]), None);
+        assert_eq!(crt(&[(1, p), (2, p - 1), (0, 1)]), None);
+    }
+
+    #[test]
+    fn test_div_rounding() {
+        assert_eq!(div_floor(7, 2), 3);
+        assert_eq!(div_floor(-7, 2), -4);
+        assert_eq!(div_floor(7, -2), -4);
+        assert_eq!(div_floor(-6, 2), -3);
+        assert_eq!(div_ceil(7, 2), 4);
+        assert_eq!(div_ceil(-7, 2), -3);
+        assert_eq!(div_ceil(-7, -2), 4);
+        assert_eq!(div_ceil(6, 3), 2);
+    }
+
+    #[test]
+    fn test_solve_unique() {
+        let solutions = solve_linear_2x2([[94, 22], [34, 67]], [8400, 5400]);
+        assert_eq!(solutions, LinearSolutions::Unique(80, 40));
+        assert_eq!(solutions.cheapest_non_negative(3, 1), Some((80, 40)));
+    }
+
+    #[test]
+    fn test_solve_not_integer() {
+        let solutions = solve_linear_2x2([[26, 67], [66, 21]], [12748, 12176]);
+        assert_eq!(solutions, LinearSolutions::None);
+    }
+
+    #[test]
+    fn test_solve_negative() {
+        // x = -1, y = 2
+        let solutions = solve_linear_2x2([[1, 1], [1, 2]], [1, 3]);
+        assert_eq!(solutions, LinearSolutions::Unique(-1, 2));
+        assert_eq!(solutions.cheapest_non_negative(3, 1), None);
+    }
+
+    #[test]
+    fn test_solve_collinear() {
+        // Both equations are x + 3y = 10.
+        let solutions = solve_linear_2x2([[1, 3], [2, 6]], [10, 20]);
+        assert!(matches!(solutions, LinearSolutions::Line { .. }));
+        // Pressing the dearer first button as little as possible.
+        assert_eq!(solutions.cheapest_non_negative(3, 1), Some((1, 3)));
+        // And the other way round when it is the cheaper one.
+        assert_eq!(solutions.cheapest_non_negative(1, 4), Some((10, 0)));
+    }
+
+    #[test]
+    fn test_solve_collinear_inconsistent() {
+        let solutions = solve_linear_2x2([[1, 3], [2, 6]], [10, 21]);
+        assert_eq!(solutions, LinearSolutions::None);
+    }
+
+    #[test]
+    fn test_solve_collinear_no_integer_point() {
+        let solutions = solve_linear_2x2([[2, 4], [1, 2]], [7, 3]);
+        assert_eq!(solutions, LinearSolutions::None);
+        let solutions = solve_linear_2x2([[2, 4], [3, 6]], [6, 9]);
+        assert_eq!(solutions.cheapest_non_negative(1, 1), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_solve_collinear_no_non_negative_point() {
+        // x + y = -2 has integer solutions but none with both non-negative.
+        let solutions = solve_linear_2x2([[1, 1], [2, 2]], [-2, -4]);
+        assert_eq!(solutions.cheapest_non_negative(1, 1), None);
+    }
+
+    #[test]
+    fn test_solve_collinear_fixed_x() {
+        // 2x = 4 pins x at 2 but leaves y free, and y costs nothing.
+        let solutions = solve_linear_2x2([[2, 0], [4, 0]], [4, 8]);
+        assert_eq!(solutions.cheapest_non_negative(1, 0), Some((2, 0)));
+        assert_eq!(solutions.cheapest_non_negative(1, 1), Some((2, 0)));
+    }
+
+    #[test]
+    fn test_solve_zero_rows() {
+        assert_eq!(
+            solve_linear_2x2([[0, 0], [0, 0]], [0, 0]),
+            LinearSolutions::All
+        );
+        assert_eq!(
+            solve_linear_2x2([[0, 0], [0, 0]], [0, 1]),
+            LinearSolutions::None
+        );
+        let solutions = solve_linear_2x2([[0, 0], [2, 3]], [0, 12]);
+        assert_eq!(solutions.cheapest_non_negative(1, 1), Some((0, 4)));
+    }
+}
@@ -0,0 +1,141 @@
+use std::{collections::HashMap, fmt::Display, hash::Hash, rc::Rc};
+
+/// A memoized recursive function.
+///
+/// The recurrence is given once, as a closure that receives the memo itself
+/// and calls [`Memo::get`] on it for its subproblems:
+///
+/// ```
+/// use aoc24::memo::Memo;
+///
+/// let mut fib = Memo::new(|fib: &mut Memo<u64, u64>, n| {
+///     if n < 2 {
+///         n
+///     } else {
+///         fib.get(n - 1) + fib.get(n - 2)
+///     }
+/// });
+/// assert_eq!(fib.get(90), 2880067194370816120);
+/// ```
+pub struct Memo<'a, K, V> {
+    cache: HashMap<K, V>,
+    f: Rc<MemoFn<'a, K, V>>,
+    stats: MemoStats,
+}
+
+type MemoFn<'a, K, V> = dyn Fn(&mut Memo<'a, K, V>, K) -> V + 'a;
+
+/// How often a [`Memo`] found its answer in the cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl Display for MemoStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let total = self.hits + self.misses;
+        let rate = if total == 0 {
+            0.0
+        } else {
+            100.0 * self.hits as f64 / total as f64
+        };
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate)",
+            self.hits, self.misses, rate
+        )
+    }
+}
+
+impl<'a, K: Hash + Eq + Clone, V: Clone> Memo<'a, K, V> {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&mut Memo<'a, K, V>, K) -> V + 'a,
+    {
+        Self {
+            cache: HashMap::new(),
+            f: Rc::new(f),
+            stats: MemoStats::default(),
+        }
+    }
+
+    /// Returns the value for `key`, computing it only if it is not cached yet.
+    pub fn get(&mut self, key: K) -> V {
+        if let Some(v) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return v.clone();
+        }
+        self.stats.misses += 1;
+
+        // The function is shared rather than borrowed from `self`,
+        // since it needs `self` mutably to recurse.
+        let f = Rc::clone(&self.f);
+        let v = f(self, key.clone());
+        self.cache.insert(key, v.clone());
+        v
+    }
+
+    /// The number of cached values.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let mut fib = Memo::new(|fib: &mut Memo<u64, u64>, n| {
+            if n < 2 {
+                n
+            } else {
+                fib.get(n - 1) + fib.get(n - 2)
+            }
+        });
+        assert_eq!(fib.get(10), 55);
+        // Each of 0..=10 is computed once; every other lookup of
+        // n - 2 finds the value its sibling n - 1 already computed.
+        assert_eq!(
+            fib.stats(),
+            MemoStats {
+                hits: 8,
+                misses: 11
+            }
+        );
+        assert_eq!(fib.len(), 11);
+
+        assert_eq!(fib.get(10), 55);
+        assert_eq!(fib.stats().hits, 9);
+    }
+
+    #[test]
+    fn test_borrows_context() {
+        let costs = [3, 1, 4, 1, 5];
+        // The cheapest way to reach the end, stepping 1 or 2 at a time.
+        let mut cheapest = Memo::new(|cheapest: &mut Memo<usize, u32>, i: usize| {
+            if i >= costs.len() {
+                0
+            } else {
+                costs[i] + cheapest.get(i + 1).min(cheapest.get(i + 2))
+            }
+        });
+        assert_eq!(cheapest.get(0), 5);
+    }
+
+    #[test]
+    fn test_stats_display() {
+        let stats = MemoStats { hits: 3, misses: 1 };
+        assert_eq!(stats.to_string(), "3 hits, 1 misses (75.0% hit rate)");
+    }
+}
@@ -0,0 +1,175 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Write},
+    io,
+};
+
+use crate::{grid::Grid, position::Position};
+
+/// A colour for overlays, drawn with ANSI escape codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Colour {
+    fn ansi_code(self) -> u8 {
+        match self {
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+        }
+    }
+}
+
+/// A picture of a grid with one glyph per cell,
+/// optionally with marked positions drawn over the top.
+///
+/// Rendering goes through `Display`, so it can be written
+/// to a string, a formatter or (with [`Render::write_io`]) a terminal.
+pub struct Render<'a, T, F> {
+    grid: &'a Grid<T>,
+    glyph: F,
+    overlay: HashMap<Position, (char, Option<Colour>)>,
+    ansi: bool,
+}
+
+impl<'a, T, F> Render<'a, T, F>
+where
+    F: Fn(&T) -> char,
+{
+    pub fn new(grid: &'a Grid<T>, glyph: F) -> Self {
+        Self {
+            grid,
+            glyph,
+            overlay: HashMap::new(),
+            ansi: false,
+        }
+    }
+
+    /// Draws `glyph` at each of `positions`, over anything drawn there before.
+    /// Positions outside the grid are ignored.
+    pub fn overlay<I>(self, positions: I, glyph: char) -> Self
+    where
+        I: IntoIterator<Item = Position>,
+    {
+        self.mark(positions, glyph, None)
+    }
+
+    /// Like [`Render::overlay`], in `colour` when ANSI output is on.
+    pub fn overlay_coloured<I>(self, positions: I, glyph: char, colour: Colour) -> Self
+    where
+        I: IntoIterator<Item = Position>,
+    {
+        self.mark(positions, glyph, Some(colour))
+    }
+
+    fn mark<I>(mut self, positions: I, glyph: char, colour: Option<Colour>) -> Self
+    where
+        I: IntoIterator<Item = Position>,
+    {
+        for p in positions {
+            if self.grid.is_in_bounds(&p) {
+                self.overlay.insert(p, (glyph, colour));
+            }
+        }
+        self
+    }
+
+    /// Whether coloured overlays use ANSI escape codes. Off by default,
+    /// so the output stays plain text unless it is going to a terminal.
+    pub fn ansi(mut self, ansi: bool) -> Self {
+        self.ansi = ansi;
+        self
+    }
+
+    pub fn write_to<W: Write>(&self, w: &mut W) -> fmt::Result {
+        write!(w, "{}", self)
+    }
+
+    pub fn write_io<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        write!(w, "{}", self)
+    }
+}
+
+impl<T, F> Display for Render<'_, T, F>
+where
+    F: Fn(&T) -> char,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (p, cell) in self.grid.enumerate() {
+            match self.overlay.get(&p) {
+                Some(&(c, Some(colour))) if self.ansi => {
+                    write!(f, "\x1b[{}m{}\x1b[0m", colour.ansi_code(), c)?
+                }
+                Some(&(c, _)) => f.write_char(c)?,
+                None => f.write_char((self.glyph)(cell))?,
+            }
+            if p.x as usize + 1 == self.grid.width {
+                f.write_char('\n')?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::position::pos;
+
+    fn maze() -> Grid<bool> {
+        Grid::parse_with("#.#\n...\n#.#\n", |c| Some(c == '#')).unwrap()
+    }
+
+    #[test]
+    fn test_glyphs() {
+        let g = maze();
+        let r = Render::new(&g, |&wall| if wall { '█' } else { ' ' });
+        assert_eq!(r.to_string(), "█ █\n   \n█ █\n");
+    }
+
+    #[test]
+    fn test_overlays() {
+        let g = maze();
+        let r = Render::new(&g, |&wall| if wall { '#' } else { '.' })
+            .overlay([pos(1, 0), pos(1, 1), pos(1, 2)], 'o')
+            .overlay([pos(1, 2), pos(5, 5), pos(-1, 0)], '@');
+        assert_eq!(r.to_string(), "#o#\n.o.\n#@#\n");
+    }
+
+    #[test]
+    fn test_ansi() {
+        let g = maze();
+        let plain = Render::new(&g, |&wall| if wall { '#' } else { '.' })
+            .overlay_coloured([pos(0, 1)], 'o', Colour::Red)
+            .overlay([pos(2, 1)], 'x');
+        assert_eq!(plain.to_string(), "#.#\no.x\n#.#\n");
+
+        let coloured = plain.ansi(true);
+        assert_eq!(coloured.to_string(), "#.#\n\x1b[31mo\x1b[0m.x\n#.#\n");
+    }
+
+    #[test]
+    fn test_write_to() {
+        let g = maze();
+        let r = Render::new(&g, |&wall| if wall { '#' } else { '.' });
+
+        let mut s = String::from("> ");
+        r.write_to(&mut s).unwrap();
+        assert_eq!(s, "> #.#\n...\n#.#\n");
+
+        let mut bytes = Vec::new();
+        r.write_io(&mut bytes).unwrap();
+        assert_eq!(bytes, b"#.#\n...\n#.#\n");
+    }
+}
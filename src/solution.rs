@@ -0,0 +1,42 @@
+use std::fmt::Display;
+
+use crate::error::{Error, ParseError, SolveError};
+
+/// A solver for both parts of a single day's puzzle.
+///
+/// The puzzle input is parsed once by `parse`
+/// and the result is shared by both parts.
+/// Malformed input is reported by `parse` as a `ParseError`;
+/// well-formed input with no answer is reported by the parts as a `SolveError`.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError>;
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError>;
+}
+
+/// Answer type for a part that isn't solved in code,
+/// such as day 24 part 2 which was solved by hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+pub fn run_part1<S: Solution>(input: &str) -> Result<String, Error> {
+    let input = S::parse(input)?;
+    Ok(S::part1(&input)?.to_string())
+}
+
+pub fn run_part2<S: Solution>(input: &str) -> Result<String, Error> {
+    let input = S::parse(input)?;
+    Ok(S::part2(&input)?.to_string())
+}
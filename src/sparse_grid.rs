@@ -0,0 +1,289 @@
+use std::collections::HashMap;
+
+use crate::{
+    direction::{DIRECTIONS, EIGHT_WAY_DIRECTIONS},
+    grid::Grid,
+    position::{pos, Position},
+};
+
+/// An unbounded grid where every cell holds `default` until it is set.
+///
+/// Only the cells that have been set are stored, so positions may be
+/// negative or far apart. The bounds grow to cover every cell set so far.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Position, T>,
+    default: T,
+    bounds: Option<(Position, Position)>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// The number of cells that have been set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The top-left and bottom-right corners of the smallest rectangle
+    /// holding every cell set so far, or `None` if nothing has been set.
+    ///
+    /// Removing cells does not shrink the bounds.
+    pub fn bounds(&self) -> Option<(Position, Position)> {
+        self.bounds
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.x - min.x + 1) as usize)
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.y - min.y + 1) as usize)
+    }
+
+    /// Whether `pos` lies within the bounds.
+    pub fn is_in_bounds(&self, pos: &Position) -> bool {
+        self.bounds.is_some_and(|(min, max)| {
+            (min.x..=max.x).contains(&pos.x) && (min.y..=max.y).contains(&pos.y)
+        })
+    }
+
+    /// Whether the cell at `pos` has been set.
+    pub fn contains(&self, pos: &Position) -> bool {
+        self.cells.contains_key(pos)
+    }
+
+    pub fn get(&self, pos: &Position) -> &T {
+        self.cells.get(pos).unwrap_or(&self.default)
+    }
+
+    pub fn set(&mut self, pos: Position, val: T) {
+        self.include(pos);
+        self.cells.insert(pos, val);
+    }
+
+    /// Resets the cell at `pos` to the default, returning what was there.
+    pub fn remove(&mut self, pos: &Position) -> Option<T> {
+        self.cells.remove(pos)
+    }
+
+    fn include(&mut self, p: Position) {
+        self.bounds = Some(match self.bounds {
+            None => (p, p),
+            Some((min, max)) => (
+                pos(min.x.min(p.x), min.y.min(p.y)),
+                pos(max.x.max(p.x), max.y.max(p.y)),
+            ),
+        });
+    }
+
+    /// The cells that have been set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.cells.iter().map(|(p, v)| (*p, v))
+    }
+
+    /// Every position within the bounds, row by row.
+    pub fn pos_iter(&self) -> impl Iterator<Item = Position> {
+        let (min, max) = self.bounds.unwrap_or((pos(0, 0), pos(-1, -1)));
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| pos(x, y)))
+    }
+
+    /// Since the grid has no edges, every position has all four neighbours.
+    pub fn neighbours(&self, pos: Position) -> impl Iterator<Item = (Position, &T)> {
+        DIRECTIONS.iter().map(move |d| {
+            let p = pos.move_in_direction(*d);
+            (p, self.get(&p))
+        })
+    }
+
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = (Position, &T)> {
+        EIGHT_WAY_DIRECTIONS.iter().map(move |d| {
+            let p = pos.move_in_direction8(*d);
+            (p, self.get(&p))
+        })
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// The cell at `pos`, set to the default first if it has not been set yet.
+    pub fn get_mut(&mut self, pos: Position) -> &mut T {
+        self.include(pos);
+        self.cells
+            .entry(pos)
+            .or_insert_with(|| self.default.clone())
+    }
+
+    /// A dense copy of the cells within the bounds,
+    /// with the top-left corner of the bounds at (0, 0).
+    pub fn to_grid(&self) -> Grid<T> {
+        let width = self.width();
+        Grid {
+            width,
+            vec: self.pos_iter().map(|p| self.get(&p).clone()).collect(),
+        }
+    }
+}
+
+impl<T: PartialEq> SparseGrid<T> {
+    /// Stores the cells of `grid` that differ from `default`,
+    /// keeping the whole of `grid` within the bounds.
+    pub fn from_grid(grid: Grid<T>, default: T) -> Self {
+        let mut sparse = Self::new(default);
+        if grid.width > 0 && grid.height() > 0 {
+            sparse.include(pos(0, 0));
+            sparse.include(pos(grid.width as i64 - 1, grid.height() as i64 - 1));
+        }
+        for (p, v) in grid.into_enumerate() {
+            if v != sparse.default {
+                sparse.cells.insert(p, v);
+            }
+        }
+        sparse
+    }
+}
+
+impl<T: Default> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> Extend<(Position, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Position, T)>>(&mut self, iter: I) {
+        for (p, v) in iter {
+            self.set(p, v);
+        }
+    }
+}
+
+impl<T: Default> FromIterator<(Position, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Position, T)>>(iter: I) -> Self {
+        let mut sparse = Self::default();
+        sparse.extend(iter);
+        sparse
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse_grid_get_set() {
+        let mut g = SparseGrid::new('.');
+        assert_eq!(g.get(&pos(3, -7)), &'.');
+        assert!(!g.contains(&pos(3, -7)));
+
+        g.set(pos(3, -7), '#');
+        assert_eq!(g.get(&pos(3, -7)), &'#');
+        assert!(g.contains(&pos(3, -7)));
+        assert_eq!(g.len(), 1);
+
+        assert_eq!(g.remove(&pos(3, -7)), Some('#'));
+        assert_eq!(g.get(&pos(3, -7)), &'.');
+        assert!(g.is_empty());
+    }
+
+    #[test]
+    fn test_sparse_grid_bounds() {
+        let mut g = SparseGrid::new(0);
+        assert_eq!(g.bounds(), None);
+        assert_eq!((g.width(), g.height()), (0, 0));
+        assert!(!g.is_in_bounds(&pos(0, 0)));
+
+        g.set(pos(2, 1), 1);
+        assert_eq!(g.bounds(), Some((pos(2, 1), pos(2, 1))));
+
+        g.set(pos(-1, 4), 1);
+        g.set(pos(0, -2), 1);
+        assert_eq!(g.bounds(), Some((pos(-1, -2), pos(2, 4))));
+        assert_eq!((g.width(), g.height()), (4, 7));
+        assert!(g.is_in_bounds(&pos(-1, -2)));
+        assert!(g.is_in_bounds(&pos(2, 4)));
+        assert!(!g.is_in_bounds(&pos(3, 0)));
+        assert!(!g.is_in_bounds(&pos(0, -3)));
+
+        // Bounds only grow.
+        g.remove(&pos(-1, 4));
+        assert_eq!(g.bounds(), Some((pos(-1, -2), pos(2, 4))));
+    }
+
+    #[test]
+    fn test_sparse_grid_get_mut() {
+        let mut counts = SparseGrid::new(0);
+        for p in [pos(1, 1), pos(-5, 0), pos(1, 1)] {
+            *counts.get_mut(p) += 1;
+        }
+        assert_eq!(counts.get(&pos(1, 1)), &2);
+        assert_eq!(counts.get(&pos(-5, 0)), &1);
+        assert_eq!(counts.get(&pos(0, 0)), &0);
+        assert_eq!(counts.bounds(), Some((pos(-5, 0), pos(1, 1))));
+    }
+
+    #[test]
+    fn test_sparse_grid_neighbours() {
+        let g = [(pos(0, -1), 'a'), (pos(-1, -1), 'b')]
+            .into_iter()
+            .collect::<SparseGrid<char>>();
+
+        let v = g.neighbours(pos(0, 0)).collect::<Vec<_>>();
+        assert_eq!(
+            v,
+            vec![
+                (pos(0, -1), &'a'),
+                (pos(1, 0), &'\0'),
+                (pos(0, 1), &'\0'),
+                (pos(-1, 0), &'\0')
+            ]
+        );
+
+        let v8 = g.neighbours8(pos(0, 0)).collect::<Vec<_>>();
+        assert_eq!(v8.len(), 8);
+        assert!(v8.contains(&(pos(-1, -1), &'b')));
+    }
+
+    #[test]
+    fn test_sparse_grid_pos_iter() {
+        let mut g = SparseGrid::new(false);
+        assert_eq!(g.pos_iter().count(), 0);
+
+        g.set(pos(-1, 0), true);
+        g.set(pos(0, 1), true);
+        let v = g.pos_iter().collect::<Vec<_>>();
+        assert_eq!(v, vec![pos(-1, 0), pos(0, 0), pos(-1, 1), pos(0, 1)]);
+    }
+
+    #[test]
+    fn test_sparse_grid_to_grid() {
+        let mut g = SparseGrid::new('.');
+        g.set(pos(-2, -1), '#');
+        g.set(pos(0, 1), '@');
+
+        let dense = g.to_grid();
+        assert_eq!(dense.to_string(), "#..\n...\n..@\n");
+
+        assert_eq!(SparseGrid::<char>::new('.').to_grid().vec, vec![]);
+    }
+
+    #[test]
+    fn test_sparse_grid_from_grid() {
+        let dense = Grid::try_from_str("...\n.#.\n").unwrap();
+        let g = SparseGrid::from_grid(dense.clone(), '.');
+        assert_eq!(g.len(), 1);
+        assert_eq!(g.get(&pos(1, 1)), &'#');
+        assert_eq!(g.bounds(), Some((pos(0, 0), pos(2, 1))));
+        assert_eq!(g.to_grid(), dense);
+    }
+}
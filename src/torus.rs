@@ -0,0 +1,228 @@
+use crate::{
+    direction::{Direction, DIRECTIONS, EIGHT_WAY_DIRECTIONS},
+    grid::Grid,
+    position::{pos, Position},
+};
+
+/// A board whose edges wrap around, so that anything leaving one side
+/// comes back in on the opposite side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Torus {
+    pub width: i64,
+    pub height: i64,
+}
+
+impl Torus {
+    pub fn new(width: i64, height: i64) -> Self {
+        assert!(
+            width > 0 && height > 0,
+            "a {}x{} board has no cells",
+            width,
+            height
+        );
+        Self { width, height }
+    }
+
+    /// The position on the board that `p` wraps around to.
+    pub fn wrap(&self, p: Position) -> Position {
+        pos(p.x.rem_euclid(self.width), p.y.rem_euclid(self.height))
+    }
+
+    /// Where something at `p` ends up after moving by `step` `times` times.
+    ///
+    /// Each axis is reduced before multiplying,
+    /// so large `times` cannot overflow.
+    pub fn advance(&self, p: Position, step: Position, times: i64) -> Position {
+        let along = |start: i64, step: i64, len: i64| {
+            (start + step.rem_euclid(len) * times.rem_euclid(len)).rem_euclid(len)
+        };
+        pos(
+            along(p.x, step.x, self.width),
+            along(p.y, step.y, self.height),
+        )
+    }
+
+    pub fn move_in_direction(&self, p: Position, d: Direction) -> Position {
+        self.wrap(p.move_in_direction(d))
+    }
+
+    /// The four neighbours of `p`, which always exist on a torus.
+    pub fn neighbours(&self, p: Position) -> impl Iterator<Item = Position> + '_ {
+        DIRECTIONS
+            .iter()
+            .map(move |d| self.wrap(p.move_in_direction(*d)))
+    }
+
+    pub fn neighbours8(&self, p: Position) -> impl Iterator<Item = Position> + '_ {
+        EIGHT_WAY_DIRECTIONS
+            .iter()
+            .map(move |d| self.wrap(p.move_in_direction8(*d)))
+    }
+
+    /// Which quadrant `p` (wrapped onto the board) is in:
+    /// 0 top-left, 1 top-right, 2 bottom-left, 3 bottom-right.
+    ///
+    /// On a board with an odd width or height the middle column or row
+    /// belongs to no quadrant.
+    pub fn quadrant(&self, p: Position) -> Option<usize> {
+        let p = self.wrap(p);
+        let x = half(p.x, self.width)?;
+        let y = half(p.y, self.height)?;
+        Some(y * 2 + x)
+    }
+
+    /// How many of `positions` are in each quadrant, indexed as in [`Torus::quadrant`].
+    pub fn quadrant_counts<I>(&self, positions: I) -> [usize; 4]
+    where
+        I: IntoIterator<Item = Position>,
+    {
+        let mut counts = [0; 4];
+        for q in positions.into_iter().filter_map(|p| self.quadrant(p)) {
+            counts[q] += 1;
+        }
+        counts
+    }
+}
+
+// Which half of 0..len the coordinate is in, if not the middle.
+fn half(v: i64, len: i64) -> Option<usize> {
+    if len % 2 == 1 && v == len / 2 {
+        None
+    } else {
+        Some(usize::from(v >= len / 2))
+    }
+}
+
+/// A view of a grid as a torus, where every position is valid
+/// and refers to the cell it wraps around to.
+pub struct WrappingGrid<'a, T> {
+    grid: &'a Grid<T>,
+    torus: Torus,
+}
+
+impl<'a, T> WrappingGrid<'a, T> {
+    /// Panics if the grid has no cells.
+    pub fn new(grid: &'a Grid<T>) -> Self {
+        Self {
+            grid,
+            torus: Torus::new(grid.width as i64, grid.height() as i64),
+        }
+    }
+
+    pub fn torus(&self) -> Torus {
+        self.torus
+    }
+
+    pub fn get(&self, p: &Position) -> &'a T {
+        self.grid.get_pos(&self.torus.wrap(*p))
+    }
+
+    pub fn neighbours(&self, p: Position) -> impl Iterator<Item = (Position, &'a T)> + '_ {
+        self.torus.neighbours(p).map(|q| (q, self.get(&q)))
+    }
+
+    pub fn neighbours8(&self, p: Position) -> impl Iterator<Item = (Position, &'a T)> + '_ {
+        self.torus.neighbours8(p).map(|q| (q, self.get(&q)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap() {
+        let t = Torus::new(11, 7);
+        assert_eq!(t.wrap(pos(3, 4)), pos(3, 4));
+        assert_eq!(t.wrap(pos(11, 7)), pos(0, 0));
+        assert_eq!(t.wrap(pos(-1, -1)), pos(10, 6));
+        assert_eq!(t.wrap(pos(-23, 15)), pos(10, 1));
+    }
+
+    #[test]
+    fn test_advance() {
+        let t = Torus::new(11, 7);
+        // The robot from the puzzle description.
+        let (start, step) = (pos(2, 4), pos(2, -3));
+        let trail = (0..=5)
+            .map(|n| t.advance(start, step, n))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            trail,
+            vec![
+                pos(2, 4),
+                pos(4, 1),
+                pos(6, 5),
+                pos(8, 2),
+                pos(10, 6),
+                pos(1, 3)
+            ]
+        );
+
+        assert_eq!(t.advance(start, step, 77), start);
+        assert_eq!(t.advance(start, step, -1), pos(0, 0));
+        assert_eq!(
+            t.advance(start, step, i64::MAX),
+            t.advance(start, step, i64::MAX % 77)
+        );
+    }
+
+    #[test]
+    fn test_move_and_neighbours() {
+        let t = Torus::new(3, 2);
+        assert_eq!(t.move_in_direction(pos(0, 0), Direction::Left), pos(2, 0));
+        assert_eq!(t.move_in_direction(pos(1, 1), Direction::Down), pos(1, 0));
+
+        let v = t.neighbours(pos(0, 0)).collect::<Vec<_>>();
+        assert_eq!(v, vec![pos(0, 1), pos(1, 0), pos(0, 1), pos(2, 0)]);
+        assert_eq!(t.neighbours8(pos(2, 1)).count(), 8);
+        assert!(t.neighbours8(pos(2, 1)).any(|p| p == pos(0, 0)));
+    }
+
+    #[test]
+    fn test_quadrant() {
+        let t = Torus::new(5, 4);
+        assert_eq!(t.quadrant(pos(0, 0)), Some(0));
+        assert_eq!(t.quadrant(pos(4, 1)), Some(1));
+        assert_eq!(t.quadrant(pos(1, 2)), Some(2));
+        assert_eq!(t.quadrant(pos(3, 3)), Some(3));
+        // The middle column of an odd width is in no quadrant,
+        // but an even height splits cleanly.
+        assert_eq!(t.quadrant(pos(2, 0)), None);
+        assert_eq!(t.quadrant(pos(-1, -1)), Some(3));
+
+        let counts = t.quadrant_counts([pos(0, 0), pos(1, 1), pos(2, 2), pos(4, 3)]);
+        assert_eq!(counts, [2, 0, 0, 1]);
+    }
+
+    #[test]
+    fn test_wrapping_grid() {
+        let g = Grid::try_from_str("abc\ndef\n").unwrap();
+        let w = g.wrapping();
+        assert_eq!(w.torus(), Torus::new(3, 2));
+        assert_eq!(w.get(&pos(-1, 0)), &'c');
+        assert_eq!(w.get(&pos(4, 3)), &'e');
+
+        let v = w.neighbours(pos(0, 0)).collect::<Vec<_>>();
+        assert_eq!(
+            v,
+            vec![
+                (pos(0, 1), &'d'),
+                (pos(1, 0), &'b'),
+                (pos(0, 1), &'d'),
+                (pos(2, 0), &'c')
+            ]
+        );
+        let letters = w
+            .neighbours8(pos(1, 0))
+            .map(|(_, c)| *c)
+            .collect::<String>();
+        assert_eq!(letters, "efcfedad");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_empty_torus() {
+        Torus::new(0, 3);
+    }
+}